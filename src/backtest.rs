@@ -0,0 +1,204 @@
+use serde::Serialize;
+
+use crate::{
+    check_ticket, seeded_rng, Exclusion, ExclusionPolicy, GameSpec, LotteryDrawing, LotteryError, LotteryRng,
+    PrizeTable, PrizeTier, TICKET_PRICE,
+};
+
+/// 과거 데이터만 보고 티켓을 만드는 번호 생성 전략
+pub trait Strategy {
+    /// 보고서에 표시할 이름
+    fn name(&self) -> String;
+
+    /// 생성에서 뺄 과거 조합 (백테스트가 회차마다 이어서 갱신해 tickets에 넘김)
+    fn exclusion_policy(&self) -> ExclusionPolicy {
+        ExclusionPolicy::default()
+    }
+
+    /// history(대상 회차 이전 회차들)와 그 회차들로 만든 제외 조합만 사용해 count장 생성
+    fn tickets(
+        &self,
+        game: &GameSpec,
+        history: &[LotteryDrawing],
+        exclusion: &Exclusion,
+        count: usize,
+        rng: &mut LotteryRng,
+    ) -> Result<Vec<[u8; 6]>, LotteryError>;
+}
+
+/// 균등 추출 (exclude_past면 기존 1등, 2등 조합 제외)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UniformStrategy {
+    pub exclude_past: bool,
+}
+
+impl Strategy for UniformStrategy {
+    fn name(&self) -> String {
+        if self.exclude_past {
+            "균등 (기존 1·2등 제외)".to_string()
+        } else {
+            "균등 (제외 없음)".to_string()
+        }
+    }
+
+    fn exclusion_policy(&self) -> ExclusionPolicy {
+        ExclusionPolicy::from_exclude_past(self.exclude_past)
+    }
+
+    fn tickets(
+        &self,
+        game: &GameSpec,
+        _history: &[LotteryDrawing],
+        exclusion: &Exclusion,
+        count: usize,
+        rng: &mut LotteryRng,
+    ) -> Result<Vec<[u8; 6]>, LotteryError> {
+        generate_sets(game, exclusion, &[], count, rng)
+    }
+}
+
+/// 필수 번호를 포함한 균등 추출 (기존 1등, 2등 조합 제외)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiredStrategy {
+    pub required: Vec<u8>,
+}
+
+impl Strategy for RequiredStrategy {
+    fn name(&self) -> String {
+        format!("필수 번호 {:?}", self.required)
+    }
+
+    fn tickets(
+        &self,
+        game: &GameSpec,
+        _history: &[LotteryDrawing],
+        exclusion: &Exclusion,
+        count: usize,
+        rng: &mut LotteryRng,
+    ) -> Result<Vec<[u8; 6]>, LotteryError> {
+        generate_sets(game, exclusion, &self.required, count, rng)
+    }
+}
+
+fn generate_sets(
+    game: &GameSpec,
+    exclusion: &Exclusion,
+    required: &[u8],
+    count: usize,
+    rng: &mut LotteryRng,
+) -> Result<Vec<[u8; 6]>, LotteryError> {
+    let tickets = game.generate(exclusion, required, count, rng)?;
+    Ok(tickets
+        .into_iter()
+        .filter_map(|ticket| ticket.numbers.try_into().ok())
+        .collect())
+}
+
+/// 백테스트 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BacktestConfig {
+    /// 검증 시작 회차 (포함)
+    pub from_round: u32,
+    /// 검증 종료 회차 (포함)
+    pub to_round: u32,
+    /// 회차마다 구매하는 티켓 수
+    pub tickets_per_round: usize,
+    pub seed: Option<u64>,
+    pub ticket_price: u64,
+    pub prizes: PrizeTable,
+}
+
+impl BacktestConfig {
+    /// 기본값: 회차당 5장, 1,000원, 평균 당첨금
+    pub fn new(from_round: u32, to_round: u32) -> BacktestConfig {
+        BacktestConfig {
+            from_round,
+            to_round,
+            tickets_per_round: 5,
+            seed: None,
+            ticket_price: TICKET_PRICE,
+            prizes: PrizeTable::default(),
+        }
+    }
+}
+
+/// 백테스트 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestReport {
+    pub strategy: String,
+    pub rounds: usize,
+    pub tickets: u64,
+    /// 등수별 당첨 횟수 (1등 ~ 5등, 낙첨 순)
+    pub hits: [u64; 6],
+    pub cost: u64,
+    pub winnings: u64,
+    pub net: i64,
+    /// 당첨금 / 구매액
+    pub return_rate: f64,
+}
+
+impl BacktestReport {
+    pub fn hits_for(&self, tier: PrizeTier) -> u64 {
+        self.hits[tier.index()]
+    }
+}
+
+/// 회차 R마다 R 이전 데이터로 티켓을 만들고 R의 결과로 채점해 합산
+///
+/// drawings는 회차순으로 정렬되어 있어야 합니다. 제외 조합은 시작 회차 이전 데이터로 한 번 만든 뒤
+/// 채점이 끝난 회차를 하나씩 더해 갱신합니다.
+pub fn backtest(
+    game: &GameSpec,
+    drawings: &[LotteryDrawing],
+    strategy: &dyn Strategy,
+    config: &BacktestConfig,
+) -> Result<BacktestReport, LotteryError> {
+    let first_index = drawings
+        .iter()
+        .position(|d| d.round >= config.from_round)
+        .ok_or(LotteryError::RoundNotFound(config.from_round))?;
+
+    let policy = strategy.exclusion_policy();
+    let mut exclusion = game.exclusion(&policy, &drawings[..first_index])?;
+    let mut rng = seeded_rng(config.seed);
+    let mut report = BacktestReport {
+        strategy: strategy.name(),
+        rounds: 0,
+        tickets: 0,
+        hits: [0; 6],
+        cost: 0,
+        winnings: 0,
+        net: 0,
+        return_rate: 0.0,
+    };
+
+    for index in first_index..drawings.len() {
+        let drawing = &drawings[index];
+        if drawing.round > config.to_round {
+            break;
+        }
+
+        let history = &drawings[..index];
+        let tickets = strategy.tickets(game, history, &exclusion, config.tickets_per_round, &mut rng)?;
+        for ticket in &tickets {
+            let tier = check_ticket(ticket, drawing)?.tier;
+            report.hits[tier.index()] += 1;
+            report.winnings += config.prizes.amount(tier);
+        }
+
+        report.rounds += 1;
+        report.tickets += tickets.len() as u64;
+        game.extend_exclusion(&mut exclusion, &policy, &drawings[..=index])?;
+    }
+
+    report.cost = report.tickets * config.ticket_price;
+    report.net = report.winnings as i64 - report.cost as i64;
+    report.return_rate = if report.cost > 0 {
+        report.winnings as f64 / report.cost as f64
+    } else {
+        0.0
+    };
+
+    Ok(report)
+}
@@ -0,0 +1,144 @@
+//! 조합 번호(combinadic) - 정렬된 조합과 정수 인덱스 사이의 일대일 변환
+//!
+//! 조합 c_1 < c_2 < ... < c_k (1부터 시작)의 인덱스는 colex 순서로
+//! Σ C(c_i - 1, i) 이며, [1, 2, 3, 4, 5, 6]은 0, [40, 41, 42, 43, 44, 45]는 8,145,059입니다.
+
+use crate::game::check_distinct_in;
+use crate::{binomial, GameSpec, LotteryError};
+
+/// 로또 6/45 전체 조합 수
+pub const TOTAL_6_45: u32 = 8_145_060;
+
+// C(n, k) (n ≤ 45, k ≤ 6) 표
+const BINOM_6_45: [[u32; 7]; 46] = binomial_table();
+
+const fn binomial_table() -> [[u32; 7]; 46] {
+    let mut table = [[0u32; 7]; 46];
+    let mut n = 0;
+    while n < 46 {
+        table[n][0] = 1;
+        let mut k = 1;
+        while k < 7 && k <= n {
+            table[n][k] = table[n - 1][k - 1] + table[n - 1][k];
+            k += 1;
+        }
+        n += 1;
+    }
+    table
+}
+
+/// 6/45 티켓의 인덱스 (0..8,145,060). 번호 순서는 상관없으며 1-45의 서로 다른 번호여야 합니다.
+pub fn rank_6_45(ticket: &[u8; 6]) -> Result<u32, LotteryError> {
+    check_distinct_in(ticket, 1..=45)?;
+
+    let mut sorted = *ticket;
+    sorted.sort();
+
+    Ok(sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &num)| BINOM_6_45[num as usize - 1][i + 1])
+        .sum())
+}
+
+/// 인덱스를 정렬된 6/45 티켓으로 변환 (index < 8,145,060)
+pub fn unrank_6_45(index: u32) -> Result<[u8; 6], LotteryError> {
+    if index >= TOTAL_6_45 {
+        return Err(LotteryError::IndexOutOfRange { index: index as u64, total: TOTAL_6_45 as u64 });
+    }
+
+    let mut ticket = [0u8; 6];
+    let mut remaining = index;
+    let mut upper = 45;
+
+    for k in (1..=6).rev() {
+        // C(c, k) <= remaining 인 가장 큰 c 찾기
+        let mut c = upper - 1;
+        while BINOM_6_45[c][k] > remaining {
+            c -= 1;
+        }
+        ticket[k - 1] = c as u8 + 1;
+        remaining -= BINOM_6_45[c][k];
+        upper = c;
+    }
+
+    Ok(ticket)
+}
+
+// 임의 크기 조합의 인덱스 (번호 순서 무관, 1부터 시작하는 서로 다른 번호, 검사는 호출자가 함)
+pub(crate) fn rank_combination(numbers: &[u8]) -> u64 {
+    let mut sorted = numbers.to_vec();
+    sorted.sort();
+
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &num)| binomial(num as u64 - 1, i as u64 + 1))
+        .sum()
+}
+
+// 인덱스를 정렬된 k개 조합으로 변환 (index < C(pool, k)는 호출자가 확인)
+pub(crate) fn unrank_combination(index: u64, k: usize) -> Vec<u8> {
+    let mut combo = vec![0u8; k];
+    let mut remaining = index;
+
+    for i in (1..=k).rev() {
+        // C(c, i) <= remaining 인 가장 큰 c 찾기 (c ≥ i - 1)
+        let mut c = i as u64 - 1;
+        while binomial(c + 1, i as u64) <= remaining {
+            c += 1;
+        }
+        combo[i - 1] = c as u8 + 1;
+        remaining -= binomial(c, i as u64);
+    }
+
+    combo
+}
+
+impl GameSpec {
+    /// 본번호 조합의 인덱스 (0..C(pool, pick))
+    pub fn rank(&self, numbers: &[u8]) -> Result<u64, LotteryError> {
+        self.check_numbers(numbers)?;
+        Ok(rank_combination(numbers))
+    }
+
+    /// 인덱스를 정렬된 본번호 조합으로 변환
+    pub fn unrank(&self, index: u64) -> Result<Vec<u8>, LotteryError> {
+        let total = binomial(self.pool as u64, self.pick as u64);
+        if index >= total {
+            return Err(LotteryError::IndexOutOfRange { index, total });
+        }
+
+        Ok(unrank_combination(index, self.pick as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_unrank_roundtrip_at_both_ends() {
+        assert_eq!(rank_6_45(&[1, 2, 3, 4, 5, 6]), Ok(0));
+        assert_eq!(rank_6_45(&[45, 44, 43, 42, 41, 40]), Ok(TOTAL_6_45 - 1));
+
+        for index in [0, 1, 4_072_530, TOTAL_6_45 - 1] {
+            let ticket = unrank_6_45(index).unwrap();
+            assert_eq!(rank_6_45(&ticket), Ok(index));
+            assert_eq!(GameSpec::lotto_645().rank(&ticket), Ok(index as u64));
+            assert_eq!(GameSpec::lotto_645().unrank(index as u64).unwrap(), ticket.to_vec());
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_rejected() {
+        assert!(matches!(rank_6_45(&[0, 2, 3, 4, 5, 6]), Err(LotteryError::NumberOutOfRange { number: 0, .. })));
+        assert!(matches!(rank_6_45(&[1, 2, 3, 4, 5, 46]), Err(LotteryError::NumberOutOfRange { number: 46, .. })));
+        assert_eq!(rank_6_45(&[1, 1, 3, 4, 5, 6]), Err(LotteryError::DuplicateNumber(1)));
+        assert_eq!(
+            unrank_6_45(TOTAL_6_45),
+            Err(LotteryError::IndexOutOfRange { index: TOTAL_6_45 as u64, total: TOTAL_6_45 as u64 })
+        );
+        assert!(GameSpec::lotto_645().rank(&[0, 2, 3, 4, 5, 6]).is_err());
+    }
+}
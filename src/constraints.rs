@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::check_distinct_in;
+use crate::{GameSpec, LotteryError};
+
+/// 생성 번호 제약 (필수 포함 번호, 제외 번호, 사용자 번호 풀)
+///
+/// WASM에서는 `{ required: [7], excluded: [4, 13, 44], pool: null }`처럼 넘기며, 생략한 항목은 제약 없음입니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NumberConstraints {
+    /// 모든 티켓에 포함할 번호
+    pub required: Vec<u8>,
+    /// 쓰지 않을 번호
+    pub excluded: Vec<u8>,
+    /// 이 번호들 안에서만 선택 (None이면 전체 번호)
+    pub pool: Option<Vec<u8>>,
+}
+
+impl NumberConstraints {
+    /// 필수 번호만 있는 제약
+    pub fn with_required(required: &[u8]) -> NumberConstraints {
+        NumberConstraints {
+            required: required.to_vec(),
+            ..NumberConstraints::default()
+        }
+    }
+
+    /// 제약이 하나도 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.required.is_empty() && self.excluded.is_empty() && self.pool.is_none()
+    }
+
+    fn allows(&self, num: u8) -> bool {
+        !self.excluded.contains(&num) && self.pool.as_ref().is_none_or(|pool| pool.contains(&num))
+    }
+}
+
+impl GameSpec {
+    /// 제약 검사 후 필수 번호 외에 고를 수 있는 번호 (오름차순)
+    ///
+    /// 고를 수 있는 번호가 부족한 경우는 오류가 아니며, 생성 시 유효 조합 0개로 보고됩니다.
+    pub fn candidate_numbers(&self, constraints: &NumberConstraints) -> Result<Vec<u8>, LotteryError> {
+        self.check_required(&constraints.required)?;
+        check_distinct_in(&constraints.excluded, self.main_range())?;
+        if let Some(pool) = &constraints.pool {
+            check_distinct_in(pool, self.main_range())?;
+        }
+
+        for &num in &constraints.required {
+            if constraints.excluded.contains(&num) {
+                return Err(LotteryError::InvalidInput(format!("{}번은 필수 번호이면서 제외 번호입니다.", num)));
+            }
+            if !constraints.allows(num) {
+                return Err(LotteryError::InvalidInput(format!("{}번은 필수 번호이지만 번호 풀에 없습니다.", num)));
+            }
+        }
+
+        Ok(self
+            .main_range()
+            .filter(|&num| !constraints.required.contains(&num) && constraints.allows(num))
+            .collect())
+    }
+}
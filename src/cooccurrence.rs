@@ -0,0 +1,261 @@
+//! 번호 쌍/삼중 동시출현
+//!
+//! 구간 안 회차의 본번호에서 두 번호(선택 시 세 번호)가 함께 나온 횟수를 셉니다.
+//! 균등 추첨에서 특정 쌍이 한 회차에 함께 나올 확률은 C(pool - 2, pick - 2) / C(pool, pick) = pick(pick - 1) / (pool(pool - 1)),
+//! 삼중은 pick(pick - 1)(pick - 2) / (pool(pool - 1)(pool - 2))이며, 기대 횟수와 z는 빈도 분석과 같은 방식으로 계산합니다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CountStats, DrawResult, GameSpec, LotteryError, RoundWindow};
+
+/// 번호 쌍 동시출현 횟수 표 (본번호 기준)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairCounts {
+    size: usize,
+    // size × size, 작은 번호가 앞
+    counts: Vec<u32>,
+}
+
+impl PairCounts {
+    /// 회차들의 본번호 쌍 횟수 (범위 밖 번호는 무시)
+    pub fn new<D: DrawResult>(game: &GameSpec, draws: &[D]) -> PairCounts {
+        PairCounts::from_draws(game, draws.iter())
+    }
+
+    fn from_draws<'a, D: DrawResult + 'a>(game: &GameSpec, draws: impl Iterator<Item = &'a D>) -> PairCounts {
+        let size = game.pool as usize + 1;
+        let mut counts = vec![0u32; size * size];
+
+        for draw in draws {
+            let numbers = sorted_main(game, draw);
+            for (i, &a) in numbers.iter().enumerate() {
+                for &b in &numbers[i + 1..] {
+                    counts[a as usize * size + b as usize] += 1;
+                }
+            }
+        }
+
+        PairCounts { size, counts }
+    }
+
+    /// 두 번호가 함께 나온 횟수 (순서 무관, 같은 번호거나 범위 밖이면 0)
+    pub fn count(&self, a: u8, b: u8) -> u32 {
+        let (a, b) = (a.min(b) as usize, a.max(b) as usize);
+        if a == 0 || a == b || b >= self.size {
+            return 0;
+        }
+        self.counts[a * self.size + b]
+    }
+
+    /// 가장 많이 함께 나온 횟수
+    pub fn max(&self) -> u32 {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+}
+
+// size³ 삼중 동시출현 횟수 (a < b < c)
+struct TripleCounts {
+    size: usize,
+    counts: Vec<u32>,
+}
+
+impl TripleCounts {
+    fn from_draws<'a, D: DrawResult + 'a>(game: &GameSpec, draws: impl Iterator<Item = &'a D>) -> TripleCounts {
+        let size = game.pool as usize + 1;
+        let mut counts = vec![0u32; size * size * size];
+
+        for draw in draws {
+            let numbers = sorted_main(game, draw);
+            for (i, &a) in numbers.iter().enumerate() {
+                for (j, &b) in numbers.iter().enumerate().skip(i + 1) {
+                    for &c in &numbers[j + 1..] {
+                        counts[(a as usize * size + b as usize) * size + c as usize] += 1;
+                    }
+                }
+            }
+        }
+
+        TripleCounts { size, counts }
+    }
+
+    fn count(&self, a: u8, b: u8, c: u8) -> u32 {
+        self.counts[(a as usize * self.size + b as usize) * self.size + c as usize]
+    }
+}
+
+fn sorted_main<D: DrawResult>(game: &GameSpec, draw: &D) -> Vec<u8> {
+    let mut numbers: Vec<u8> = draw.main_numbers().iter().copied().filter(|num| game.main_range().contains(num)).collect();
+    numbers.sort();
+    numbers.dedup();
+    numbers
+}
+
+/// 동시출현 분석 조건 (기본값은 전체 회차, 쌍만, 상위/하위 10개)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CooccurrenceQuery {
+    pub window: RoundWindow,
+    /// 삼중 동시출현도 셀지 여부
+    pub triples: bool,
+    /// 많이/적게 나온 목록 길이
+    pub top: usize,
+}
+
+impl Default for CooccurrenceQuery {
+    fn default() -> CooccurrenceQuery {
+        CooccurrenceQuery {
+            window: RoundWindow::All,
+            triples: false,
+            top: 10,
+        }
+    }
+}
+
+/// 번호 쌍 하나의 동시출현
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PairFrequency {
+    pub numbers: [u8; 2],
+    pub stats: CountStats,
+}
+
+/// 번호 삼중 하나의 동시출현
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TripleFrequency {
+    pub numbers: [u8; 3],
+    pub stats: CountStats,
+}
+
+/// 삼중 동시출현 요약
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TripleSummary {
+    /// 삼중 하나의 기대 횟수
+    pub expected: f64,
+    /// 많이 나온 순 (같으면 번호순)
+    pub most_common: Vec<TripleFrequency>,
+    /// 한 번도 함께 나오지 않은 삼중 수
+    pub never_appeared: usize,
+    /// 전체 삼중 수
+    pub total: usize,
+}
+
+/// 구간 동시출현 분석 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CooccurrenceReport {
+    pub query: CooccurrenceQuery,
+    pub from_round: u32,
+    pub to_round: u32,
+    pub draws: usize,
+    /// pool × pool 쌍 횟수 행렬 ([a - 1][b - 1], 대칭, 대각선은 0)
+    pub matrix: Vec<Vec<u32>>,
+    /// 쌍 하나의 기대 횟수
+    pub pair_expected: f64,
+    /// 많이 나온 순 (같으면 번호순)
+    pub most_common_pairs: Vec<PairFrequency>,
+    /// 한 번 이상 나온 쌍 중 적게 나온 순 (같으면 번호순)
+    pub least_common_pairs: Vec<PairFrequency>,
+    /// 한 번도 함께 나오지 않은 쌍 (번호순)
+    pub never_paired: Vec<[u8; 2]>,
+    pub triples: Option<TripleSummary>,
+}
+
+impl CooccurrenceReport {
+    /// 두 번호가 함께 나온 횟수 (순서 무관, 같은 번호거나 범위 밖이면 0)
+    pub fn pair_count(&self, a: u8, b: u8) -> u32 {
+        let pool = self.matrix.len() as u8;
+        if !(1..=pool).contains(&a) || !(1..=pool).contains(&b) {
+            return 0;
+        }
+        self.matrix[a as usize - 1][b as usize - 1]
+    }
+}
+
+impl GameSpec {
+    /// 구간 안 회차들의 번호 쌍(선택 시 삼중) 동시출현
+    pub fn cooccurrence_report<D: DrawResult>(
+        &self,
+        draws: &[D],
+        query: &CooccurrenceQuery,
+    ) -> Result<CooccurrenceReport, LotteryError> {
+        let window = query.window.select(draws)?;
+        let draws = window.len();
+
+        let pool = self.pool as f64;
+        let pick = self.pick as f64;
+        let pair_probability = pick * (pick - 1.0) / (pool * (pool - 1.0));
+        let triple_probability = pair_probability * (pick - 2.0) / (pool - 2.0);
+
+        let pairs = PairCounts::from_draws(self, window.iter().copied());
+        let mut all_pairs = Vec::new();
+        for a in self.main_range() {
+            for b in a + 1..=self.pool {
+                all_pairs.push(PairFrequency {
+                    numbers: [a, b],
+                    stats: CountStats::new(pairs.count(a, b) as usize, draws, pair_probability),
+                });
+            }
+        }
+
+        let matrix = self
+            .main_range()
+            .map(|a| self.main_range().map(|b| pairs.count(a, b)).collect())
+            .collect();
+
+        let never_paired = all_pairs.iter().filter(|pair| pair.stats.count == 0).map(|pair| pair.numbers).collect();
+
+        let mut least_common_pairs: Vec<PairFrequency> = all_pairs.iter().copied().filter(|pair| pair.stats.count > 0).collect();
+        least_common_pairs.sort_by(|a, b| a.stats.count.cmp(&b.stats.count).then(a.numbers.cmp(&b.numbers)));
+        least_common_pairs.truncate(query.top);
+
+        all_pairs.sort_by(|a, b| b.stats.count.cmp(&a.stats.count).then(a.numbers.cmp(&b.numbers)));
+        all_pairs.truncate(query.top);
+
+        let triples = query.triples.then(|| {
+            let counts = TripleCounts::from_draws(self, window.iter().copied());
+            let mut most_common = Vec::new();
+            let mut never_appeared = 0;
+            let mut total = 0;
+            for a in self.main_range() {
+                for b in a + 1..=self.pool {
+                    for c in b + 1..=self.pool {
+                        let count = counts.count(a, b, c);
+                        total += 1;
+                        if count == 0 {
+                            never_appeared += 1;
+                        } else {
+                            most_common.push(TripleFrequency {
+                                numbers: [a, b, c],
+                                stats: CountStats::new(count as usize, draws, triple_probability),
+                            });
+                        }
+                    }
+                }
+            }
+            most_common.sort_by(|a, b| b.stats.count.cmp(&a.stats.count).then(a.numbers.cmp(&b.numbers)));
+            most_common.truncate(query.top);
+
+            TripleSummary {
+                expected: draws as f64 * triple_probability,
+                most_common,
+                never_appeared,
+                total,
+            }
+        });
+
+        Ok(CooccurrenceReport {
+            query: *query,
+            from_round: window[0].round(),
+            to_round: window[draws - 1].round(),
+            draws,
+            matrix,
+            pair_expected: draws as f64 * pair_probability,
+            most_common_pairs: all_pairs,
+            least_common_pairs,
+            never_paired,
+            triples,
+        })
+    }
+}
@@ -0,0 +1,288 @@
+//! 커버링 디자인 탐색 (보장 조건을 만족하는 더 적은 티켓 찾기)
+//!
+//! 축약 휠(탐욕)로 시작해 모든 경우를 덮으면 티켓 하나를 빼고, 티켓 수를 고정한 채
+//! 덮이지 않은 경우 수를 비용으로 하는 시뮬레이티드 어닐링을 반복합니다.
+//! 한 번의 이동은 덮이지 않은 경우 하나와 티켓 하나를 골라, 그 경우에 없는 티켓 번호 하나를
+//! 그 경우의 번호로 바꾸는 것입니다. 시간 예산이나 반복 한도에 닿으면 찾은 것 중 가장 좋은 결과를 반환합니다.
+//! 시간 예산은 네이티브에서 `std::time::Instant`, wasm32에서는 `Date.now()`로 잽니다.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::game::for_each_combination;
+use crate::wheel::{check_guarantee_bounds, finish, ticket_of, usable, wheel_numbers};
+use crate::{abbreviated_wheel, seeded_rng, Exclusion, GameSpec, LotteryError, TicketMask, Wheel, WheelGuarantee};
+
+// 어닐링 시작 온도와 이동마다 곱하는 냉각 계수 (티켓을 뺄 때마다 다시 시작)
+const START_TEMPERATURE: f64 = 1.0;
+const COOLING: f64 = 0.9999;
+const MIN_TEMPERATURE: f64 = 0.3;
+
+/// 커버링 탐색 설정
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CoveringConfig {
+    /// 탐색 시간 예산 (밀리초)
+    pub time_budget_ms: u64,
+    /// 어닐링 이동 최대 횟수
+    pub max_iterations: u64,
+    pub seed: Option<u64>,
+}
+
+impl Default for CoveringConfig {
+    fn default() -> CoveringConfig {
+        CoveringConfig {
+            time_budget_ms: 3_000,
+            max_iterations: 5_000_000,
+            seed: None,
+        }
+    }
+}
+
+/// 커버링 탐색 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoveringSearch {
+    /// 찾은 것 중 가장 좋은 티켓 목록 (검증 결과 포함)
+    pub wheel: Wheel,
+    /// 시작점인 축약 휠의 티켓 수
+    pub initial_tickets: usize,
+    pub iterations: u64,
+    pub elapsed_ms: u64,
+}
+
+/// 보장 조건을 만족하는 적은 티켓 목록 탐색 (제외 대상 티켓은 쓰지 않음)
+///
+/// 시간 예산을 쓰므로 같은 시드라도 실행 속도에 따라 결과가 달라질 수 있습니다.
+pub fn search_covering(
+    game: &GameSpec,
+    pool: &[u8],
+    keys: &[u8],
+    guarantee: WheelGuarantee,
+    exclusion: &Exclusion,
+    config: &CoveringConfig,
+) -> Result<CoveringSearch, LotteryError> {
+    let stopwatch = Stopwatch::start();
+
+    let initial = abbreviated_wheel(game, pool, keys, guarantee, exclusion)?;
+    let (pool, keys) = wheel_numbers(game, pool, keys)?;
+    check_guarantee_bounds(&pool, &keys, guarantee)?;
+
+    let key_mask = TicketMask::from_numbers(&keys);
+    let blocks: Vec<TicketMask> = initial
+        .tickets
+        .iter()
+        .map(|ticket| TicketMask(TicketMask::from_numbers(ticket).0 & !key_mask.0))
+        .collect();
+
+    let mut cases = Vec::new();
+    for_each_combination(&pool, guarantee.if_drawn as usize, |case| cases.push(TicketMask::from_numbers(case)));
+
+    let mut state = Annealing::new(blocks, cases, guarantee.matched as u32, key_mask, exclusion);
+    let mut best = state.blocks.clone();
+    let mut best_uncovered = state.uncovered.len();
+    let mut rng = seeded_rng(config.seed);
+    let mut temperature = START_TEMPERATURE;
+    let mut iterations = 0;
+
+    while !state.blocks.is_empty() && iterations < config.max_iterations && stopwatch.elapsed_ms() < config.time_budget_ms {
+        if state.uncovered.is_empty() {
+            best = state.blocks.clone();
+            best_uncovered = 0;
+            if state.blocks.len() <= 1 {
+                break;
+            }
+            state.remove_weakest();
+            temperature = START_TEMPERATURE;
+            continue;
+        }
+
+        iterations += 1;
+        state.step(temperature, &mut rng);
+        temperature = (temperature * COOLING).max(MIN_TEMPERATURE);
+
+        // 티켓 수를 줄이지 못했더라도 덮은 경우가 늘었으면 기록 (제외 정책으로 100%가 불가능한 경우)
+        if state.uncovered.len() < best_uncovered && state.blocks.len() == best.len() {
+            best = state.blocks.clone();
+            best_uncovered = state.uncovered.len();
+        }
+    }
+
+    let mut tickets: Vec<[u8; 6]> = best.iter().filter_map(|&block| ticket_of(key_mask, block)).collect();
+    tickets.sort();
+    Ok(CoveringSearch {
+        wheel: finish(pool, keys, guarantee, tickets, 0),
+        initial_tickets: initial.tickets.len(),
+        iterations,
+        elapsed_ms: stopwatch.elapsed_ms(),
+    })
+}
+
+// 탐색 시작부터 경과 시간 (wasm32에는 std::time::Instant가 없어 Date.now()를 씀)
+struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: std::time::Instant,
+    #[cfg(target_arch = "wasm32")]
+    start: f64,
+}
+
+impl Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start() -> Stopwatch {
+        Stopwatch { start: std::time::Instant::now() }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn start() -> Stopwatch {
+        Stopwatch { start: js_sys::Date::now() }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn elapsed_ms(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn elapsed_ms(&self) -> u64 {
+        (js_sys::Date::now() - self.start).max(0.0) as u64
+    }
+}
+
+// 티켓 수를 고정한 어닐링 상태
+struct Annealing<'a> {
+    blocks: Vec<TicketMask>,
+    cases: Vec<TicketMask>,
+    matched: u32,
+    key_mask: TicketMask,
+    exclusion: &'a Exclusion,
+    // 경우별로 그 경우를 덮는 티켓 수
+    cover_counts: Vec<u32>,
+    // 덮이지 않은 경우 번호와, 경우별 그 목록 안 위치
+    uncovered: Vec<usize>,
+    positions: Vec<Option<usize>>,
+}
+
+impl<'a> Annealing<'a> {
+    fn new(
+        blocks: Vec<TicketMask>,
+        cases: Vec<TicketMask>,
+        matched: u32,
+        key_mask: TicketMask,
+        exclusion: &'a Exclusion,
+    ) -> Annealing<'a> {
+        let mut state = Annealing {
+            cover_counts: vec![0; cases.len()],
+            positions: vec![None; cases.len()],
+            uncovered: Vec::new(),
+            blocks: Vec::new(),
+            cases,
+            matched,
+            key_mask,
+            exclusion,
+        };
+
+        for index in 0..state.cases.len() {
+            state.mark_uncovered(index);
+        }
+        for block in blocks {
+            state.blocks.push(block);
+            state.apply(block, true);
+        }
+        state
+    }
+
+    fn covers(&self, block: TicketMask, case: TicketMask) -> bool {
+        block.matches(case) >= self.matched
+    }
+
+    // 티켓 하나를 더하거나 빼면서 경우별 덮는 수 갱신
+    fn apply(&mut self, block: TicketMask, add: bool) {
+        for index in 0..self.cases.len() {
+            if !self.covers(block, self.cases[index]) {
+                continue;
+            }
+            if add {
+                self.cover_counts[index] += 1;
+                if self.cover_counts[index] == 1 {
+                    self.mark_covered(index);
+                }
+            } else {
+                self.cover_counts[index] -= 1;
+                if self.cover_counts[index] == 0 {
+                    self.mark_uncovered(index);
+                }
+            }
+        }
+    }
+
+    fn mark_uncovered(&mut self, index: usize) {
+        self.positions[index] = Some(self.uncovered.len());
+        self.uncovered.push(index);
+    }
+
+    fn mark_covered(&mut self, index: usize) {
+        if let Some(position) = self.positions[index].take() {
+            self.uncovered.swap_remove(position);
+            if let Some(&moved) = self.uncovered.get(position) {
+                self.positions[moved] = Some(position);
+            }
+        }
+    }
+
+    // 혼자서만 덮는 경우가 가장 적은 티켓 제거
+    fn remove_weakest(&mut self) {
+        let weakest = (0..self.blocks.len())
+            .min_by_key(|&i| {
+                let block = self.blocks[i];
+                (0..self.cases.len())
+                    .filter(|&index| self.cover_counts[index] == 1 && self.covers(block, self.cases[index]))
+                    .count()
+            })
+            .expect("티켓이 하나 이상 있음");
+
+        let block = self.blocks.swap_remove(weakest);
+        self.apply(block, false);
+    }
+
+    // 덮이지 않은 경우 하나를 향해 티켓 하나의 번호 하나를 바꾸는 이동
+    fn step<R: Rng + ?Sized>(&mut self, temperature: f64, rng: &mut R) {
+        let case = self.cases[*self.uncovered.choose(rng).expect("덮이지 않은 경우가 있음")];
+        // 번호 하나만 바꾸면 그 경우를 덮는 티켓이 있으면 그중에서 고름
+        let near: Vec<usize> = (0..self.blocks.len())
+            .filter(|&i| self.blocks[i].matches(case) + 1 == self.matched)
+            .collect();
+        let index = match near.choose(rng) {
+            Some(&index) => index,
+            None => rng.gen_range(0..self.blocks.len()),
+        };
+        let old = self.blocks[index];
+
+        let outgoing: Vec<u8> = old.iter().filter(|&num| !case.contains(num)).collect();
+        let incoming: Vec<u8> = case.iter().filter(|&num| !old.contains(num)).collect();
+        let (Some(&out), Some(&inc)) = (outgoing.choose(rng), incoming.choose(rng)) else {
+            return;
+        };
+
+        let new = old.without(out).with(inc);
+        if !usable(self.key_mask, new, self.exclusion, &self.blocks) {
+            return;
+        }
+
+        // 덮이지 않은 경우 수의 변화량
+        let mut delta = 0i64;
+        for (i, &case) in self.cases.iter().enumerate() {
+            match (self.covers(old, case), self.covers(new, case)) {
+                (true, false) if self.cover_counts[i] == 1 => delta += 1,
+                (false, true) if self.cover_counts[i] == 0 => delta -= 1,
+                _ => {}
+            }
+        }
+
+        if delta <= 0 || rng.gen::<f64>() < (-(delta as f64) / temperature).exp() {
+            self.apply(old, false);
+            self.blocks[index] = new;
+            self.apply(new, true);
+        }
+    }
+}
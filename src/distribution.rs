@@ -0,0 +1,444 @@
+//! 티켓 특징의 정확한 이론 분포
+//!
+//! 균등 추첨에서 본번호 조합의 특징별 조합 수를 전체 조합을 나열하지 않고 정확히 계산합니다.
+//! - 합계: 번호를 하나씩 보며 (고른 개수, 합계)별 경우의 수를 세는 DP
+//! - 홀수 개수, 저번호(pool/2 이하) 개수: 초기하분포 C(a, j) × C(pool - a, pick - j)
+//! - 연속 쌍 c개: 연속 블록 b = pick - c개를 만드는 방법 C(pick - 1, b - 1) × 블록 배치 C(pool - pick + 1, b)
+//! - 십의 자리 구간 수(1-9, 10-19, ...): 구간별로 고른 개수에 대한 DP
+//! - 최대 간격(정렬한 인접 번호 차의 최댓값) m 이하: 차이가 모두 1..=m이고 합이 D인 경우의 수 × 시작 번호 pool - D가지
+//!
+//! 필터 조건의 조합 통과 비율은 조건 하나씩은 위 분포로, 모든 조건을 함께 적용한 비율은 전체 조합을 나열해 계산합니다.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::game::for_each_combination;
+use crate::{binomial, consecutive_pairs, DrawResult, Filter, FilterConfig, GameSpec, LotteryError, RoundWindow};
+
+/// 분포를 계산하는 티켓 특징
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Feature {
+    /// 본번호 합계
+    Sum,
+    /// 홀수 개수
+    OddCount,
+    /// pool/2 이하 번호 개수
+    LowCount,
+    /// 연속 번호 쌍 개수
+    ConsecutivePairs,
+    /// 번호가 걸친 십의 자리 구간 수
+    DecadeSpread,
+    /// 정렬한 인접 번호 차의 최댓값
+    MaxGap,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 6] = [
+        Feature::Sum,
+        Feature::OddCount,
+        Feature::LowCount,
+        Feature::ConsecutivePairs,
+        Feature::DecadeSpread,
+        Feature::MaxGap,
+    ];
+
+    /// 정렬된 본번호 조합의 특징 값
+    pub fn value(&self, game: &GameSpec, numbers: &[u8]) -> usize {
+        match self {
+            Feature::Sum => numbers.iter().map(|&num| num as usize).sum(),
+            Feature::OddCount => numbers.iter().filter(|&&num| num % 2 == 1).count(),
+            Feature::LowCount => numbers.iter().filter(|&&num| num <= game.pool / 2).count(),
+            Feature::ConsecutivePairs => consecutive_pairs(numbers),
+            Feature::DecadeSpread => {
+                let mut decades: Vec<u8> = numbers.iter().map(|&num| num / 10).collect();
+                decades.dedup();
+                decades.len()
+            }
+            Feature::MaxGap => numbers.windows(2).map(|pair| (pair[1] - pair[0]) as usize).max().unwrap_or(0),
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feature::Sum => write!(f, "합계"),
+            Feature::OddCount => write!(f, "홀수 개수"),
+            Feature::LowCount => write!(f, "저번호 개수"),
+            Feature::ConsecutivePairs => write!(f, "연속 쌍"),
+            Feature::DecadeSpread => write!(f, "십의 자리 구간 수"),
+            Feature::MaxGap => write!(f, "최대 간격"),
+        }
+    }
+}
+
+/// 특징 값 하나의 이론 확률과 실제 회차 수
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureBucket {
+    pub value: usize,
+    /// 이 값을 갖는 조합 수
+    pub combinations: u64,
+    pub probability: f64,
+    /// 구간 회차 수 × probability
+    pub expected: f64,
+    pub observed: usize,
+}
+
+/// 특징 하나의 이론 분포와 실제 분포
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureDistribution {
+    pub feature: Feature,
+    pub mean: f64,
+    pub observed_mean: f64,
+    /// 조합이 하나 이상 있는 값 (오름차순)
+    pub buckets: Vec<FeatureBucket>,
+}
+
+impl FeatureDistribution {
+    /// min 이상 max 이하일 이론 확률
+    pub fn probability_between(&self, min: usize, max: usize) -> f64 {
+        self.buckets
+            .iter()
+            .filter(|bucket| (min..=max).contains(&bucket.value))
+            .map(|bucket| bucket.probability)
+            .sum()
+    }
+
+    /// min 이상 max 이하였던 실제 회차 수
+    pub fn observed_between(&self, min: usize, max: usize) -> usize {
+        self.buckets
+            .iter()
+            .filter(|bucket| (min..=max).contains(&bucket.value))
+            .map(|bucket| bucket.observed)
+            .sum()
+    }
+}
+
+/// 구간 회차와 이론 분포 비교 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionReport {
+    pub window: RoundWindow,
+    pub from_round: u32,
+    pub to_round: u32,
+    pub draws: usize,
+    pub total_combinations: u64,
+    /// Feature::ALL 순서
+    pub features: Vec<FeatureDistribution>,
+}
+
+impl DistributionReport {
+    pub fn feature(&self, feature: Feature) -> Option<&FeatureDistribution> {
+        self.features.iter().find(|distribution| distribution.feature == feature)
+    }
+}
+
+/// 필터 조건 하나가 남기는 비율
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionCoverage {
+    pub name: String,
+    /// 전체 조합 중 통과 비율
+    pub kept: f64,
+    /// 구간 당첨번호 중 통과 비율 (직전 회차 겹침은 구간 첫 회차 제외)
+    pub observed_kept: f64,
+}
+
+/// 필터 설정이 남기는 조합 비율
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterCoverage {
+    pub window: RoundWindow,
+    pub draws: usize,
+    pub conditions: Vec<ConditionCoverage>,
+    /// 모든 조건을 통과하는 조합 수
+    pub kept_combinations: u64,
+    pub total_combinations: u64,
+    pub kept: f64,
+    pub observed_kept: f64,
+}
+
+impl GameSpec {
+    /// 특징별 값마다의 조합 수 (값 오름차순, 조합이 없는 값 제외)
+    pub fn feature_counts(&self, feature: Feature) -> Vec<(usize, u64)> {
+        let pool = self.pool as u64;
+        let pick = self.pick as u64;
+
+        let counts: Vec<u64> = match feature {
+            Feature::Sum => self.sum_counts(),
+            Feature::OddCount => {
+                let odd = pool.div_ceil(2);
+                (0..=pick).map(|j| binomial(odd, j) * binomial(pool - odd, pick - j)).collect()
+            }
+            Feature::LowCount => {
+                let low = pool / 2;
+                (0..=pick).map(|j| binomial(low, j) * binomial(pool - low, pick - j)).collect()
+            }
+            Feature::ConsecutivePairs => (0..pick)
+                .map(|c| {
+                    let blocks = pick - c;
+                    binomial(pick - 1, blocks - 1) * binomial(pool - pick + 1, blocks)
+                })
+                .collect(),
+            Feature::DecadeSpread => self.decade_spread_counts(),
+            Feature::MaxGap => self.max_gap_counts(),
+        };
+
+        counts
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, count)| count > 0)
+            .collect()
+    }
+
+    // 합계별 본번호 조합 수 (인덱스 = 합계)
+    pub(crate) fn sum_counts(&self) -> Vec<u64> {
+        let pick = self.pick as usize;
+        let max_sum: usize = (0..pick).map(|i| self.pool as usize - i).sum();
+
+        // ways[k][s]: 지금까지 본 번호 중 k개를 골라 합이 s인 경우의 수
+        let mut ways = vec![vec![0u64; max_sum + 1]; pick + 1];
+        ways[0][0] = 1;
+        for num in self.main_range().map(|num| num as usize) {
+            for k in (1..=pick).rev() {
+                for s in (num..=max_sum).rev() {
+                    ways[k][s] += ways[k - 1][s - num];
+                }
+            }
+        }
+
+        ways.swap_remove(pick)
+    }
+
+    // 걸친 십의 자리 구간 수별 조합 수 (인덱스 = 구간 수)
+    fn decade_spread_counts(&self) -> Vec<u64> {
+        let pick = self.pick as usize;
+        let mut sizes = vec![0u64; self.pool as usize / 10 + 1];
+        for num in self.main_range() {
+            sizes[num as usize / 10] += 1;
+        }
+
+        // ways[k][d]: 지금까지 본 구간에서 k개를 골라 d개 구간에 걸친 경우의 수
+        let mut ways = vec![vec![0u64; pick + 1]; pick + 1];
+        ways[0][0] = 1;
+        for size in sizes {
+            let mut next = vec![vec![0u64; pick + 1]; pick + 1];
+            for k in 0..=pick {
+                for (d, &count) in ways[k].iter().enumerate().take(k + 1).filter(|&(_, &count)| count > 0) {
+                    for j in 0..=(pick - k).min(size as usize) {
+                        let spread = if j > 0 { d + 1 } else { d };
+                        next[k + j][spread] += count * binomial(size, j as u64);
+                    }
+                }
+            }
+            ways = next;
+        }
+
+        ways.swap_remove(pick)
+    }
+
+    // 최대 간격별 조합 수 (인덱스 = 간격, 번호가 하나면 간격 0)
+    fn max_gap_counts(&self) -> Vec<u64> {
+        let pool = self.pool as usize;
+        let parts = self.pick as usize - 1;
+        if parts == 0 {
+            return vec![pool as u64];
+        }
+
+        let max_gap = pool - parts;
+        // 모든 인접 차가 gap 이하인 조합 수
+        let at_most = |gap: usize| -> u64 {
+            // ways[D]: 지금까지의 차 합이 D인 경우의 수
+            let mut ways = vec![0u64; pool];
+            ways[0] = 1;
+            for _ in 0..parts {
+                let mut next = vec![0u64; pool];
+                for (total, &count) in ways.iter().enumerate().filter(|&(_, &count)| count > 0) {
+                    for step in 1..=gap.min(pool - 1 - total) {
+                        next[total + step] += count;
+                    }
+                }
+                ways = next;
+            }
+            ways.iter().enumerate().map(|(total, &count)| count * (pool - total) as u64).sum()
+        };
+
+        let mut counts = vec![0u64; max_gap + 1];
+        let mut previous = 0;
+        for (gap, count) in counts.iter_mut().enumerate().skip(1) {
+            let current = at_most(gap);
+            *count = current - previous;
+            previous = current;
+        }
+        counts
+    }
+
+    /// 구간 회차의 특징 분포를 이론 분포와 비교
+    pub fn distribution_report<D: DrawResult>(&self, draws: &[D], window: RoundWindow) -> Result<DistributionReport, LotteryError> {
+        let selected = window.select(draws)?;
+        let tickets: Vec<Vec<u8>> = selected.iter().map(|draw| self.sorted_main(*draw)).collect();
+        let total = binomial(self.pool as u64, self.pick as u64);
+        let n = tickets.len();
+
+        let features = Feature::ALL
+            .iter()
+            .map(|&feature| {
+                let values: Vec<usize> = tickets.iter().map(|numbers| feature.value(self, numbers)).collect();
+                let buckets: Vec<FeatureBucket> = self
+                    .feature_counts(feature)
+                    .into_iter()
+                    .map(|(value, combinations)| {
+                        let probability = combinations as f64 / total as f64;
+                        FeatureBucket {
+                            value,
+                            combinations,
+                            probability,
+                            expected: n as f64 * probability,
+                            observed: values.iter().filter(|&&observed| observed == value).count(),
+                        }
+                    })
+                    .collect();
+
+                FeatureDistribution {
+                    feature,
+                    mean: buckets.iter().map(|bucket| bucket.value as f64 * bucket.probability).sum(),
+                    observed_mean: values.iter().sum::<usize>() as f64 / n as f64,
+                    buckets,
+                }
+            })
+            .collect();
+
+        Ok(DistributionReport {
+            window,
+            from_round: selected[0].round(),
+            to_round: selected[n - 1].round(),
+            draws: n,
+            total_combinations: total,
+            features,
+        })
+    }
+
+    /// 필터 설정이 남기는 조합 비율과 구간 당첨번호 통과 비율
+    ///
+    /// previous는 생성할 때 쓰는 직전 회차 1등 번호이며, 없으면 겹침 조건을 생략합니다.
+    pub fn filter_coverage<D: DrawResult>(
+        &self,
+        draws: &[D],
+        window: RoundWindow,
+        config: &FilterConfig,
+        previous: Option<&[u8]>,
+    ) -> Result<FilterCoverage, LotteryError> {
+        let selected = window.select(draws)?;
+        let tickets: Vec<Vec<u8>> = selected.iter().map(|draw| self.sorted_main(*draw)).collect();
+        let total = binomial(self.pool as u64, self.pick as u64);
+        let share = |passed: usize, of: usize| if of > 0 { passed as f64 / of as f64 } else { 0.0 };
+
+        // (이론 통과 비율, 조건)
+        let mut conditions: Vec<(f64, Box<dyn Filter>)> = Vec::new();
+        if let Some(sum) = config.sum {
+            let kept = self.feature_share(Feature::Sum, sum.min as usize, sum.max as usize);
+            conditions.push((kept, Box::new(sum)));
+        }
+        if let Some(odd) = config.odd {
+            let kept = self.feature_share(Feature::OddCount, odd.min, odd.max);
+            conditions.push((kept, Box::new(odd)));
+        }
+        if let Some(low) = config.low {
+            let pool = self.pool as u64;
+            let pick = self.pick as u64;
+            let below = (low.threshold.min(self.pool)) as u64;
+            let kept = (low.min as u64..=(low.max as u64).min(pick))
+                .map(|j| binomial(below, j) * binomial(pool - below, pick - j))
+                .sum::<u64>() as f64
+                / total as f64;
+            conditions.push((kept, Box::new(low)));
+        }
+        if let Some(consecutive) = config.consecutive {
+            let kept = self.feature_share(Feature::ConsecutivePairs, 0, consecutive.max);
+            conditions.push((kept, Box::new(consecutive)));
+        }
+
+        let mut coverage: Vec<ConditionCoverage> = conditions
+            .iter()
+            .map(|(kept, filter)| ConditionCoverage {
+                name: filter.name(),
+                kept: *kept,
+                observed_kept: share(tickets.iter().filter(|numbers| filter.accepts(numbers)).count(), tickets.len()),
+            })
+            .collect();
+
+        // 겹침 조건은 이론 비율을 초기하분포로, 실제 비율을 구간 안 연속 회차로 계산
+        let overlap = match (config.previous_overlap, previous) {
+            (Some(max), Some(previous)) => Some((max, previous)),
+            _ => None,
+        };
+        if let Some((max, previous)) = overlap {
+            let pool = self.pool as u64;
+            let pick = self.pick as u64;
+            let shared = previous.len() as u64;
+            let kept = (0..=(max as u64).min(pick))
+                .map(|j| binomial(shared, j) * binomial(pool - shared, pick - j))
+                .sum::<u64>() as f64
+                / total as f64;
+            let passed = tickets.windows(2).filter(|pair| overlap_count(&pair[0], &pair[1]) <= max).count();
+            coverage.push(ConditionCoverage {
+                name: format!("직전 회차와 {}개 이하 겹침", max),
+                kept,
+                observed_kept: share(passed, tickets.len() - 1),
+            });
+        }
+
+        let filter = config.build(overlap.map(|(_, previous)| previous));
+        let numbers: Vec<u8> = self.main_range().collect();
+        let mut kept_combinations = 0;
+        for_each_combination(&numbers, self.pick as usize, |combo| {
+            if filter.accepts(combo) {
+                kept_combinations += 1;
+            }
+        });
+
+        // 모든 조건 실제 통과: 겹침 조건이 있으면 각 회차의 직전 회차 기준 (구간 첫 회차 제외)
+        let observed_passed = match overlap {
+            Some((max, _)) => tickets
+                .windows(2)
+                .filter(|pair| conditions.iter().all(|(_, filter)| filter.accepts(&pair[1])) && overlap_count(&pair[0], &pair[1]) <= max)
+                .count(),
+            None => tickets.iter().filter(|numbers| conditions.iter().all(|(_, filter)| filter.accepts(numbers))).count(),
+        };
+        let observed_of = if overlap.is_some() { tickets.len() - 1 } else { tickets.len() };
+
+        Ok(FilterCoverage {
+            window,
+            draws: tickets.len(),
+            conditions: coverage,
+            kept_combinations,
+            total_combinations: total,
+            kept: kept_combinations as f64 / total as f64,
+            observed_kept: share(observed_passed, observed_of),
+        })
+    }
+
+    // 특징 값이 min 이상 max 이하인 조합 비율
+    fn feature_share(&self, feature: Feature, min: usize, max: usize) -> f64 {
+        let total = binomial(self.pool as u64, self.pick as u64) as f64;
+        self.feature_counts(feature)
+            .into_iter()
+            .filter(|(value, _)| (min..=max).contains(value))
+            .map(|(_, count)| count as f64)
+            .sum::<f64>()
+            / total
+    }
+
+    fn sorted_main<D: DrawResult>(&self, draw: &D) -> Vec<u8> {
+        let mut numbers: Vec<u8> = draw.main_numbers().iter().copied().filter(|num| self.main_range().contains(num)).collect();
+        numbers.sort();
+        numbers
+    }
+}
+
+fn overlap_count(previous: &[u8], numbers: &[u8]) -> usize {
+    numbers.iter().filter(|num| previous.contains(num)).count()
+}
@@ -0,0 +1,128 @@
+//! 다양성 선택 (MMR 방식)
+//!
+//! 점수순 후보 목록에서 한 장씩 고르되, 조정 점수 = 점수 - lambda × (이미 고른 티켓에서 해당 번호들이 쓰인 횟수 합)이
+//! 가장 높은 후보를 고릅니다. 한 번호가 max_per_number번 쓰였으면 그 번호를 포함한 후보는 건너뛰며,
+//! 모든 후보가 상한에 걸리면 그 한 장에 한해 상한을 풀어 고릅니다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ScoredTicket;
+
+/// 다양성 선택 설정
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DiversityConfig {
+    /// 번호 재사용 감점 계수
+    pub lambda: f64,
+    /// 번호 하나가 등장할 수 있는 최대 티켓 수
+    pub max_per_number: usize,
+    /// 선택 대상으로 삼을 점수 상위 후보 수
+    pub pool_size: usize,
+}
+
+impl Default for DiversityConfig {
+    fn default() -> DiversityConfig {
+        DiversityConfig {
+            lambda: 0.15,
+            max_per_number: 4,
+            pool_size: 1_500,
+        }
+    }
+}
+
+/// 선택된 티켓들의 번호 사용 통계
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiversityStats {
+    /// 한 번 이상 쓰인 서로 다른 번호 수
+    pub unique_numbers: usize,
+    /// 번호별 등장 티켓 수 (많은 순, 같으면 번호순)
+    pub number_counts: Vec<(u8, usize)>,
+}
+
+impl DiversityStats {
+    /// 티켓 목록의 번호 사용 통계
+    pub fn from_tickets(tickets: &[ScoredTicket]) -> DiversityStats {
+        let mut usage = [0usize; 64];
+        for ticket in tickets {
+            for &num in &ticket.numbers {
+                usage[num as usize] += 1;
+            }
+        }
+
+        let mut number_counts: Vec<(u8, usize)> = usage
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(num, &count)| (num as u8, count))
+            .collect();
+        number_counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        DiversityStats {
+            unique_numbers: number_counts.len(),
+            number_counts,
+        }
+    }
+}
+
+/// 다양성 선택 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiverseSelection {
+    pub tickets: Vec<ScoredTicket>,
+    pub stats: DiversityStats,
+}
+
+/// 점수순(내림차순) 후보 목록에서 번호가 고르게 섞이도록 target장 선택
+///
+/// 후보 목록의 앞 pool_size개만 사용하며, 후보가 부족하면 target보다 적게 반환합니다.
+pub fn diverse_select(candidates: &[ScoredTicket], target: usize, config: &DiversityConfig) -> DiverseSelection {
+    let mut remaining: Vec<ScoredTicket> = candidates.iter().take(config.pool_size).copied().collect();
+    let mut selected = Vec::with_capacity(target);
+    let mut usage = [0usize; 64];
+
+    let adjusted = |ticket: &ScoredTicket, usage: &[usize; 64]| {
+        let used: usize = ticket.numbers.iter().map(|&n| usage[n as usize]).sum();
+        ticket.score - config.lambda * used as f64
+    };
+
+    while !remaining.is_empty() && selected.len() < target {
+        let capped = |ticket: &ScoredTicket| ticket.numbers.iter().any(|&n| usage[n as usize] >= config.max_per_number);
+
+        // 상한에 걸리지 않는 후보 중 최고점, 없으면 상한 없이 최고점 (동점이면 앞선 후보)
+        let best = best_index(&remaining, |ticket| !capped(ticket), |ticket| adjusted(ticket, &usage))
+            .or_else(|| best_index(&remaining, |_| true, |ticket| adjusted(ticket, &usage)));
+
+        let Some(index) = best else {
+            break;
+        };
+
+        let ticket = remaining.remove(index);
+        for &num in &ticket.numbers {
+            usage[num as usize] += 1;
+        }
+        selected.push(ticket);
+    }
+
+    DiverseSelection {
+        stats: DiversityStats::from_tickets(&selected),
+        tickets: selected,
+    }
+}
+
+fn best_index(
+    tickets: &[ScoredTicket],
+    allowed: impl Fn(&ScoredTicket) -> bool,
+    score: impl Fn(&ScoredTicket) -> f64,
+) -> Option<usize> {
+    let mut best: Option<(usize, f64)> = None;
+
+    for (index, ticket) in tickets.iter().enumerate().filter(|(_, ticket)| allowed(ticket)) {
+        let value = score(ticket);
+        if best.is_none_or(|(_, best_value)| value > best_value) {
+            best = Some((index, value));
+        }
+    }
+
+    best.map(|(index, _)| index)
+}
@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{DrawResult, GameSpec, LotteryError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LotteryDrawing {
+    pub round: u32,
+    pub numbers: [u8; 6],
+    pub bonus: u8,
+}
+
+impl LotteryDrawing {
+    /// 번호 범위, 중복, 보너스 충돌을 검사한 뒤 회차 생성
+    pub fn new(round: u32, numbers: [u8; 6], bonus: u8) -> Result<LotteryDrawing, LotteryError> {
+        let drawing = LotteryDrawing { round, numbers, bonus };
+        drawing.check()?;
+        Ok(drawing)
+    }
+
+    /// 단일 회차 검사 (1-45 범위, 번호 중복, 보너스 충돌)
+    pub fn check(&self) -> Result<(), LotteryError> {
+        GameSpec::lotto_645().check_draw(self)
+    }
+
+    /// 정렬된 1등 번호
+    pub fn sorted_numbers(&self) -> [u8; 6] {
+        let mut sorted = self.numbers;
+        sorted.sort();
+        sorted
+    }
+}
+
+/// 데이터셋 검증에서 발견된 문제
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ValidationIssue {
+    /// 같은 회차가 동일한 내용으로 중복 저장됨
+    DuplicateRound { round: u32 },
+    /// 같은 회차에 서로 다른 번호가 저장됨
+    ConflictingRound { round: u32 },
+    /// 회차 순서에서 빠진 회차 구간 from-to (경고)
+    MissingRounds { from: u32, to: u32 },
+    /// 1-45 범위를 벗어난 번호
+    NumberOutOfRange { round: u32, number: u8 },
+    /// 한 회차 안에서 중복된 번호
+    DuplicateNumber { round: u32, number: u8 },
+    /// 보너스 번호가 1등 번호와 같음
+    BonusCollision { round: u32, bonus: u8 },
+    /// 본번호 또는 보너스 번호 개수가 게임 정의와 다름
+    InvalidNumberCount { round: u32 },
+}
+
+impl ValidationIssue {
+    /// 데이터 로드를 막지 않는 경고 여부
+    pub fn is_warning(&self) -> bool {
+        matches!(self, ValidationIssue::MissingRounds { .. })
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::DuplicateRound { round } => write!(f, "{}회차 중복 저장", round),
+            ValidationIssue::ConflictingRound { round } => {
+                write!(f, "{}회차에 서로 다른 번호가 저장됨", round)
+            }
+            ValidationIssue::MissingRounds { from, to } if from == to => write!(f, "{}회차 누락", from),
+            ValidationIssue::MissingRounds { from, to } => write!(f, "{}-{}회차 누락", from, to),
+            ValidationIssue::NumberOutOfRange { round, number } => {
+                write!(f, "{}회차: 범위를 벗어난 번호 {}", round, number)
+            }
+            ValidationIssue::DuplicateNumber { round, number } => {
+                write!(f, "{}회차: 중복된 번호 {}", round, number)
+            }
+            ValidationIssue::BonusCollision { round, bonus } => {
+                write!(f, "{}회차: 보너스 번호 {}가 1등 번호와 같음", round, bonus)
+            }
+            ValidationIssue::InvalidNumberCount { round } => {
+                write!(f, "{}회차: 번호 개수가 게임 정의와 다름", round)
+            }
+        }
+    }
+}
+
+/// 전체 데이터셋 검증 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// 경고를 제외한 오류가 없는지 여부
+    pub fn is_valid(&self) -> bool {
+        self.issues.iter().all(|issue| issue.is_warning())
+    }
+
+    /// 경고 목록 (빠진 회차 등)
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|issue| issue.is_warning())
+    }
+
+    /// 오류만 담은 LotteryError로 변환
+    pub fn into_result(self) -> Result<(), LotteryError> {
+        if self.is_valid() {
+            return Ok(());
+        }
+
+        let issues = self.issues.into_iter().filter(|issue| !issue.is_warning()).collect();
+        Err(LotteryError::InvalidDataset { issues })
+    }
+}
+
+/// 전체 회차 데이터 검증 (로또 6/45 기준)
+pub fn validate(drawings: &[LotteryDrawing]) -> ValidationReport {
+    validate_with(&GameSpec::lotto_645(), drawings)
+}
+
+/// 주어진 게임 정의로 전체 회차 데이터 검증
+pub fn validate_with<D: DrawResult>(game: &GameSpec, drawings: &[D]) -> ValidationReport {
+    let mut issues = Vec::new();
+    let mut by_round: BTreeMap<u32, Vec<&D>> = BTreeMap::new();
+
+    for drawing in drawings {
+        let round = drawing.round();
+        by_round.entry(round).or_default().push(drawing);
+
+        match game.check_draw(drawing) {
+            Ok(()) => {}
+            Err(LotteryError::NumberOutOfRange { number, .. }) => {
+                issues.push(ValidationIssue::NumberOutOfRange { round, number });
+            }
+            Err(LotteryError::DuplicateNumber(number)) => {
+                issues.push(ValidationIssue::DuplicateNumber { round, number });
+            }
+            Err(LotteryError::BonusCollision(bonus)) => {
+                issues.push(ValidationIssue::BonusCollision { round, bonus });
+            }
+            Err(_) => {
+                issues.push(ValidationIssue::InvalidNumberCount { round });
+            }
+        }
+    }
+
+    // 같은 회차 중복/충돌
+    for (&round, records) in &by_round {
+        if records.len() < 2 {
+            continue;
+        }
+
+        let key = |d: &D| {
+            let mut numbers = d.main_numbers().to_vec();
+            numbers.sort();
+            (numbers, d.bonus_numbers().to_vec())
+        };
+        let first = key(records[0]);
+        let identical = records.iter().all(|d| key(d) == first);
+
+        if identical {
+            issues.push(ValidationIssue::DuplicateRound { round });
+        } else {
+            issues.push(ValidationIssue::ConflictingRound { round });
+        }
+    }
+
+    // 빠진 회차 (연속된 회차는 한 구간으로)
+    let rounds: Vec<u32> = by_round.keys().copied().collect();
+    for pair in rounds.windows(2) {
+        if pair[1] - pair[0] > 1 {
+            issues.push(ValidationIssue::MissingRounds { from: pair[0] + 1, to: pair[1] - 1 });
+        }
+    }
+
+    ValidationReport { issues }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drawing(round: u32) -> LotteryDrawing {
+        LotteryDrawing::new(round, [1, 2, 3, 4, 5, 6], 7).unwrap()
+    }
+
+    #[test]
+    fn missing_rounds_are_reported_as_ranges() {
+        let drawings = [drawing(1), drawing(2), drawing(5), drawing(7), drawing(4_000_000_000)];
+        let report = validate(&drawings);
+
+        assert_eq!(
+            report.issues,
+            vec![
+                ValidationIssue::MissingRounds { from: 3, to: 4 },
+                ValidationIssue::MissingRounds { from: 6, to: 6 },
+                ValidationIssue::MissingRounds { from: 8, to: 3_999_999_999 },
+            ]
+        );
+        assert!(report.is_valid());
+    }
+}
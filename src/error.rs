@@ -0,0 +1,223 @@
+use serde::Serialize;
+use std::fmt;
+use wasm_bindgen::JsValue;
+
+use crate::ValidationIssue;
+
+/// 로또 엔진에서 발생하는 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LotteryError {
+    /// 필수 번호 개수가 1-max개 범위를 벗어남 (max는 게임의 선택 개수)
+    RequiredCountOutOfRange { max: u8 },
+    /// 번호가 게임의 번호 범위(min-max)를 벗어남
+    NumberOutOfRange { number: u8, min: u8, max: u8 },
+    /// 같은 번호가 두 번 이상 입력됨
+    DuplicateNumber(u8),
+    /// 보너스 번호가 1등 번호와 같음
+    BonusCollision(u8),
+    /// 이미 저장된 회차
+    DuplicateRound(u32),
+    /// 데이터셋 검증 실패
+    InvalidDataset { issues: Vec<ValidationIssue> },
+    /// 엔진이 지원하지 않는 게임 정의
+    UnsupportedGame(String),
+    /// 조합 인덱스가 전체 조합 수를 벗어남
+    IndexOutOfRange { index: u64, total: u64 },
+    /// 저장되지 않은 회차
+    RoundNotFound(u32),
+    /// JS 등 외부 입력 형식 오류
+    InvalidInput(String),
+    /// 번호 개수가 맞지 않음
+    InvalidNumberCount { expected: usize, found: usize },
+    /// 조건에 맞는 조합을 요청한 개수만큼 찾지 못함
+    InsufficientCombinations { requested: usize, found: usize },
+    /// 데이터 파싱 실패 (line은 1부터 시작)
+    Parse { line: usize, reason: String },
+}
+
+impl LotteryError {
+    /// 프론트엔드/서비스에서 분기용으로 사용하는 고정 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            LotteryError::RequiredCountOutOfRange { .. } => "REQUIRED_COUNT_OUT_OF_RANGE",
+            LotteryError::NumberOutOfRange { .. } => "NUMBER_OUT_OF_RANGE",
+            LotteryError::DuplicateNumber(_) => "DUPLICATE_NUMBER",
+            LotteryError::BonusCollision(_) => "BONUS_COLLISION",
+            LotteryError::DuplicateRound(_) => "DUPLICATE_ROUND",
+            LotteryError::InvalidDataset { .. } => "INVALID_DATASET",
+            LotteryError::UnsupportedGame(_) => "UNSUPPORTED_GAME",
+            LotteryError::IndexOutOfRange { .. } => "INDEX_OUT_OF_RANGE",
+            LotteryError::RoundNotFound(_) => "ROUND_NOT_FOUND",
+            LotteryError::InvalidInput(_) => "INVALID_INPUT",
+            LotteryError::InvalidNumberCount { .. } => "INVALID_NUMBER_COUNT",
+            LotteryError::InsufficientCombinations { .. } => "INSUFFICIENT_COMBINATIONS",
+            LotteryError::Parse { .. } => "PARSE",
+        }
+    }
+
+    fn to_js_error(&self) -> JsError {
+        let mut js_error = JsError {
+            code: self.code(),
+            message: self.to_string(),
+            number: None,
+            min: None,
+            max: None,
+            round: None,
+            issues: None,
+            index: None,
+            total: None,
+            expected: None,
+            requested: None,
+            found: None,
+            line: None,
+            reason: None,
+        };
+
+        match self {
+            LotteryError::RequiredCountOutOfRange { max } => {
+                js_error.min = Some(1);
+                js_error.max = Some(*max);
+            }
+            LotteryError::NumberOutOfRange { number, min, max } => {
+                js_error.number = Some(*number);
+                js_error.min = Some(*min);
+                js_error.max = Some(*max);
+            }
+            LotteryError::DuplicateNumber(number)
+            | LotteryError::BonusCollision(number) => {
+                js_error.number = Some(*number);
+            }
+            LotteryError::DuplicateRound(round) | LotteryError::RoundNotFound(round) => {
+                js_error.round = Some(*round);
+            }
+            LotteryError::InvalidInput(reason) => {
+                js_error.reason = Some(reason.clone());
+            }
+            LotteryError::InvalidDataset { issues } => {
+                js_error.issues = Some(issues.clone());
+            }
+            LotteryError::UnsupportedGame(name) => {
+                js_error.reason = Some(name.clone());
+            }
+            LotteryError::IndexOutOfRange { index, total } => {
+                js_error.index = Some(*index);
+                js_error.total = Some(*total);
+            }
+            LotteryError::InvalidNumberCount { expected, found } => {
+                js_error.expected = Some(*expected);
+                js_error.found = Some(*found);
+            }
+            LotteryError::InsufficientCombinations { requested, found } => {
+                js_error.requested = Some(*requested);
+                js_error.found = Some(*found);
+            }
+            LotteryError::Parse { line, reason } => {
+                js_error.line = Some(*line);
+                js_error.reason = Some(reason.clone());
+            }
+        }
+
+        js_error
+    }
+}
+
+impl fmt::Display for LotteryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LotteryError::RequiredCountOutOfRange { max } => {
+                write!(f, "필수 번호는 1-{}개 사이여야 합니다.", max)
+            }
+            LotteryError::NumberOutOfRange { number, min, max } => {
+                write!(f, "번호는 {}-{} 사이여야 합니다. (입력: {})", min, max, number)
+            }
+            LotteryError::DuplicateNumber(number) => {
+                write!(f, "중복된 번호가 있습니다. (번호: {})", number)
+            }
+            LotteryError::BonusCollision(bonus) => {
+                write!(f, "보너스 번호가 1등 번호와 같습니다. (번호: {})", bonus)
+            }
+            LotteryError::DuplicateRound(round) => {
+                write!(f, "{}회차는 이미 저장되어 있습니다.", round)
+            }
+            LotteryError::InvalidDataset { issues } => {
+                write!(f, "데이터 검증 오류 {}건", issues.len())?;
+                if let Some(first) = issues.first() {
+                    write!(f, " (첫 번째: {})", first)?;
+                }
+                Ok(())
+            }
+            LotteryError::UnsupportedGame(name) => {
+                write!(f, "지원하지 않는 게임입니다: {}", name)
+            }
+            LotteryError::IndexOutOfRange { index, total } => {
+                write!(f, "조합 인덱스 {}가 범위를 벗어났습니다. (전체 {}개)", index, total)
+            }
+            LotteryError::RoundNotFound(round) => {
+                write!(f, "{}회차 데이터가 없습니다.", round)
+            }
+            LotteryError::InvalidInput(reason) => {
+                write!(f, "입력 형식 오류: {}", reason)
+            }
+            LotteryError::InvalidNumberCount { expected, found } => {
+                write!(f, "{}개의 번호를 입력해주세요. ({}개 입력됨)", expected, found)
+            }
+            LotteryError::InsufficientCombinations { requested, found } => write!(
+                f,
+                "조건에 맞는 번호 조합을 {}개 찾을 수 없습니다. (유효한 조합 {}개)",
+                requested, found
+            ),
+            LotteryError::Parse { line, reason } => {
+                write!(f, "{}번째 줄 파싱 오류: {}", line, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LotteryError {}
+
+/// JS로 전달되는 오류 객체 ({ code, message, ... })
+#[derive(Serialize)]
+struct JsError {
+    code: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    round: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issues: Option<Vec<ValidationIssue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requested: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    found: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+impl From<LotteryError> for JsValue {
+    fn from(error: LotteryError) -> JsValue {
+        serde_wasm_bindgen::to_value(&error.to_js_error())
+            .unwrap_or_else(|_| JsValue::from_str(&error.to_string()))
+    }
+}
+
+impl From<serde_json::Error> for LotteryError {
+    fn from(error: serde_json::Error) -> LotteryError {
+        LotteryError::Parse {
+            line: error.line(),
+            reason: error.to_string(),
+        }
+    }
+}
@@ -0,0 +1,333 @@
+//! 티켓 1장의 기대값
+//!
+//! 4, 5등은 고정 당첨금이고 1-3등은 등수별 당첨금 풀을 당첨자끼리 나눕니다(패리뮤추얼).
+//! 나 외의 당첨자 수를 평균 co_winners인 포아송 분포로 보고 1인당 몫의 기대값을 구하며
+//! (평균이 10,000명을 넘으면 정규분포 근사),
+//! 세금은 몫마다 적용한 뒤 평균을 내므로 세전/세후 기대값을 따로 보고합니다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{binomial, BonusRule, GameSpec, LotteryError, PrizeTier, TICKET_PRICE};
+
+/// 패리뮤추얼 등수의 예상 당첨금 풀
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrizePool {
+    /// 등수 전체 당첨금 (원, 이월금 포함)
+    pub pool: u64,
+    /// 나를 제외한 예상 당첨자 수
+    pub co_winners: f64,
+}
+
+/// 당첨금 세율 (기본값은 한국 복권 당첨금 세율)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrizeTax {
+    /// 이 금액 이하는 비과세 (원)
+    pub exempt_limit: u64,
+    /// 기본 세율 구간 상한 (원)
+    pub bracket_limit: u64,
+    /// 상한 이하 세율 (소득세 + 지방소득세)
+    pub base_rate: f64,
+    /// 상한 초과분 세율
+    pub high_rate: f64,
+}
+
+impl Default for PrizeTax {
+    fn default() -> PrizeTax {
+        PrizeTax {
+            exempt_limit: 2_000_000,
+            bracket_limit: 300_000_000,
+            base_rate: 0.22,
+            high_rate: 0.33,
+        }
+    }
+}
+
+impl PrizeTax {
+    /// 당첨금에 붙는 세금 (티켓 가격은 필요경비로 공제)
+    pub fn tax(&self, prize: f64, ticket_price: u64) -> f64 {
+        if prize <= self.exempt_limit as f64 {
+            return 0.0;
+        }
+
+        let taxable = (prize - ticket_price as f64).max(0.0);
+        let limit = self.bracket_limit as f64;
+        taxable.min(limit) * self.base_rate + (taxable - limit).max(0.0) * self.high_rate
+    }
+
+    /// 세후 수령액
+    pub fn net(&self, prize: f64, ticket_price: u64) -> f64 {
+        prize - self.tax(prize, ticket_price)
+    }
+}
+
+/// 한 회차의 등수별 당첨자 수와 1인당 당첨금 (1-5등 순서)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundPrizes {
+    pub round: u32,
+    pub winners: [u64; 5],
+    pub amounts: [u64; 5],
+}
+
+/// 기대값 계산 입력
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EvParams {
+    pub ticket_price: u64,
+    pub first: PrizePool,
+    pub second: PrizePool,
+    pub third: PrizePool,
+    /// 4등 고정 당첨금
+    pub fourth: u64,
+    /// 5등 고정 당첨금
+    pub fifth: u64,
+    pub tax: PrizeTax,
+}
+
+impl Default for EvParams {
+    fn default() -> EvParams {
+        EvParams {
+            ticket_price: TICKET_PRICE,
+            first: PrizePool { pool: 25_000_000_000, co_winners: 11.0 },
+            second: PrizePool { pool: 5_000_000_000, co_winners: 80.0 },
+            third: PrizePool { pool: 5_000_000_000, co_winners: 3_000.0 },
+            fourth: 50_000,
+            fifth: 5_000,
+            tax: PrizeTax::default(),
+        }
+    }
+}
+
+impl EvParams {
+    /// 지난 회차 당첨 결과를 다음 회차 예상치로 사용 (당첨자가 없던 등수는 기본값 유지)
+    pub fn from_round_prizes(prizes: &RoundPrizes) -> EvParams {
+        let mut params = EvParams::default();
+        let pools = [&mut params.first, &mut params.second, &mut params.third];
+
+        for (i, pool) in pools.into_iter().enumerate() {
+            if prizes.winners[i] > 0 {
+                *pool = PrizePool {
+                    pool: prizes.winners[i] * prizes.amounts[i],
+                    co_winners: prizes.winners[i] as f64,
+                };
+            }
+        }
+        if prizes.winners[3] > 0 {
+            params.fourth = prizes.amounts[3];
+        }
+        if prizes.winners[4] > 0 {
+            params.fifth = prizes.amounts[4];
+        }
+
+        params
+    }
+
+    fn pool(&self, tier: PrizeTier) -> Option<&PrizePool> {
+        match tier {
+            PrizeTier::First => Some(&self.first),
+            PrizeTier::Second => Some(&self.second),
+            PrizeTier::Third => Some(&self.third),
+            _ => None,
+        }
+    }
+
+    fn fixed_prize(&self, tier: PrizeTier) -> u64 {
+        match tier {
+            PrizeTier::Fourth => self.fourth,
+            PrizeTier::Fifth => self.fifth,
+            _ => 0,
+        }
+    }
+}
+
+/// 등수별 기대값
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TierEv {
+    pub tier: PrizeTier,
+    pub probability: f64,
+    /// 당첨 시 1인당 받는 금액의 기대값 (세전)
+    pub gross_prize: f64,
+    /// 당첨 시 1인당 받는 금액의 기대값 (세후)
+    pub net_prize: f64,
+    /// probability × gross_prize
+    pub gross_ev: f64,
+    /// probability × net_prize
+    pub net_ev: f64,
+}
+
+/// 티켓 1장의 기대값 계산 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvReport {
+    pub ticket_price: u64,
+    /// 1-5등
+    pub tiers: Vec<TierEv>,
+    /// 기대 당첨금 (세전)
+    pub gross_return: f64,
+    /// 기대 당첨금 (세후)
+    pub net_return: f64,
+    /// 기대 손익 = 기대 당첨금 - 티켓 가격 (세전)
+    pub gross_ev: f64,
+    /// 기대 손익 (세후)
+    pub net_ev: f64,
+    /// 기대 당첨금 / 티켓 가격 (세전)
+    pub gross_return_rate: f64,
+    /// 기대 당첨금 / 티켓 가격 (세후)
+    pub net_return_rate: f64,
+}
+
+impl EvReport {
+    /// 세후 기대 손익이 양수인지 여부
+    pub fn is_favorable(&self) -> bool {
+        self.net_ev > 0.0
+    }
+}
+
+/// 티켓 1장의 등수별 당첨 확률 (PrizeTier::index 순서, 6개 선택 + 같은 추첨기 보너스 1개 게임)
+pub fn tier_probabilities(game: &GameSpec) -> Result<[f64; 6], LotteryError> {
+    if game.pick != 6 || game.bonus != (BonusRule::SameDrum { count: 1 }) {
+        return Err(LotteryError::UnsupportedGame(game.name.clone()));
+    }
+
+    // 당첨번호가 아닌 번호 수 (보너스 포함)
+    let others = game.pool as u64 - 6;
+    let total = binomial(game.pool as u64, 6) as f64;
+
+    let first = 1;
+    let second = 6;
+    let third = 6 * (others - 1);
+    let fourth = binomial(6, 4) * binomial(others, 2);
+    let fifth = binomial(6, 3) * binomial(others, 3);
+    let counts = [first, second, third, fourth, fifth];
+
+    let mut probabilities = [0.0; 6];
+    for (probability, count) in probabilities.iter_mut().zip(counts) {
+        *probability = count as f64 / total;
+    }
+    probabilities[5] = 1.0 - probabilities[..5].iter().sum::<f64>();
+
+    Ok(probabilities)
+}
+
+/// 티켓 1장의 세전/세후 기대값
+pub fn expected_value(game: &GameSpec, params: &EvParams) -> Result<EvReport, LotteryError> {
+    let probabilities = tier_probabilities(game)?;
+    let price = params.ticket_price;
+
+    let mut tiers = Vec::with_capacity(5);
+    for tier in PrizeTier::ALL.into_iter().filter(|tier| tier.is_winning()) {
+        let probability = probabilities[tier.index()];
+
+        let (gross_prize, net_prize) = match params.pool(tier) {
+            Some(pool) => {
+                if !(pool.co_winners >= 0.0 && pool.co_winners.is_finite()) {
+                    return Err(LotteryError::InvalidInput(format!(
+                        "{} 예상 당첨자 수: {}", tier, pool.co_winners
+                    )));
+                }
+                let pool_size = pool.pool as f64;
+                (
+                    expected_share(pool_size, pool.co_winners, |share| share),
+                    expected_share(pool_size, pool.co_winners, |share| params.tax.net(share, price)),
+                )
+            }
+            None => {
+                let prize = params.fixed_prize(tier) as f64;
+                (prize, params.tax.net(prize, price))
+            }
+        };
+
+        tiers.push(TierEv {
+            tier,
+            probability,
+            gross_prize,
+            net_prize,
+            gross_ev: probability * gross_prize,
+            net_ev: probability * net_prize,
+        });
+    }
+
+    let gross_return: f64 = tiers.iter().map(|t| t.gross_ev).sum();
+    let net_return: f64 = tiers.iter().map(|t| t.net_ev).sum();
+
+    Ok(EvReport {
+        ticket_price: price,
+        tiers,
+        gross_return,
+        net_return,
+        gross_ev: gross_return - price as f64,
+        net_ev: net_return - price as f64,
+        gross_return_rate: gross_return / price as f64,
+        net_return_rate: net_return / price as f64,
+    })
+}
+
+// 평균 당첨자 수가 이보다 크면 포아송 합 대신 정규분포 근사 사용 (합의 항 수가 평균에 비례하므로)
+const POISSON_SUM_LIMIT: f64 = 10_000.0;
+
+// 다른 당첨자 수 K ~ Poisson(mean)일 때 E[f(pool / (K + 1))]
+fn expected_share(pool: f64, mean: f64, f: impl Fn(f64) -> f64) -> f64 {
+    if mean == 0.0 {
+        return f(pool);
+    }
+    if mean > POISSON_SUM_LIMIT {
+        return normal_share(pool, mean, f);
+    }
+
+    // 평균에서 충분히 먼 꼬리는 무시 (로그 공간에서 확률 계산)
+    let upper = (mean + 12.0 * mean.sqrt() + 20.0).ceil() as u64;
+    let ln_mean = mean.ln();
+    let mut ln_p = -mean;
+    let mut total = 0.0;
+    let mut mass = 0.0;
+
+    for k in 0..=upper {
+        if k > 0 {
+            ln_p += ln_mean - (k as f64).ln();
+        }
+        let p = ln_p.exp();
+        total += p * f(pool / (k + 1) as f64);
+        mass += p;
+    }
+
+    total / mass
+}
+
+// K ≈ N(mean, mean)로 보고 z = -8..8을 0.05 간격으로 적분 (항 수 고정)
+fn normal_share(pool: f64, mean: f64, f: impl Fn(f64) -> f64) -> f64 {
+    let sd = mean.sqrt();
+    let mut total = 0.0;
+    let mut mass = 0.0;
+
+    for i in -160..=160 {
+        let z = i as f64 / 20.0;
+        let weight = (-0.5 * z * z).exp();
+        let k = (mean + z * sd).max(0.0);
+        total += weight * f(pool / (k + 1.0));
+        mass += weight;
+    }
+
+    total / mass
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normal_share_agrees_with_poisson_sum_at_the_limit() {
+        let pool = 25_000_000_000.0;
+        let exact = expected_share(pool, POISSON_SUM_LIMIT, |share| share);
+        let approx = normal_share(pool, POISSON_SUM_LIMIT, |share| share);
+        assert!((exact - approx).abs() / exact < 1e-6, "{} vs {}", exact, approx);
+    }
+
+    #[test]
+    fn huge_co_winners_finish_quickly() {
+        let share = expected_share(25_000_000_000.0, 1e12, |share| share);
+        assert!((share - 0.025).abs() < 1e-4, "{}", share);
+    }
+}
@@ -0,0 +1,259 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::game::for_each_combination;
+use crate::{DrawResult, GameSpec, LotteryError};
+
+/// 과거 당첨 조합 제외 정책
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ExclusionPolicy {
+    /// 제외 없음
+    None,
+    /// 기존 1등 조합
+    FirstPlace,
+    /// 기존 1등 + 2등 조합 (1등 번호 하나를 보너스로 교체)
+    #[default]
+    SecondPlace,
+    /// 기존 1등 번호와 5개 이상 겹치는 조합 (1-3등 당첨 조합)
+    ThirdPlace,
+    /// 최근 draws회 중 한 회차와 shared개 이상 겹치는 조합
+    Recent { draws: usize, shared: u8 },
+}
+
+impl ExclusionPolicy {
+    /// 기존 1등, 2등 조합 제외 여부 (이전 기본 동작과의 호환용)
+    pub fn from_exclude_past(exclude_past: bool) -> ExclusionPolicy {
+        if exclude_past {
+            ExclusionPolicy::SecondPlace
+        } else {
+            ExclusionPolicy::None
+        }
+    }
+}
+
+impl fmt::Display for ExclusionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExclusionPolicy::None => write!(f, "제외 없음"),
+            ExclusionPolicy::FirstPlace => write!(f, "기존 1등 당첨번호 제외"),
+            ExclusionPolicy::SecondPlace => write!(f, "기존 1등, 2등 당첨번호 제외"),
+            ExclusionPolicy::ThirdPlace => write!(f, "기존 1등 번호와 5개 이상 겹치는 조합 제외"),
+            ExclusionPolicy::Recent { draws, shared } => {
+                write!(f, "최근 {}회 당첨번호와 {}개 이상 겹치는 조합 제외", draws, shared)
+            }
+        }
+    }
+}
+
+/// "none", "first", "second", "third", "recent:N:K" (최근 N회와 K개 이상 겹침)
+impl FromStr for ExclusionPolicy {
+    type Err = LotteryError;
+
+    fn from_str(s: &str) -> Result<ExclusionPolicy, LotteryError> {
+        let invalid = || LotteryError::InvalidInput(format!("제외 정책 '{}'", s));
+        let parts: Vec<&str> = s.trim().split(':').collect();
+
+        match parts.as_slice() {
+            ["none"] => Ok(ExclusionPolicy::None),
+            ["first"] => Ok(ExclusionPolicy::FirstPlace),
+            ["second"] => Ok(ExclusionPolicy::SecondPlace),
+            ["third"] => Ok(ExclusionPolicy::ThirdPlace),
+            ["recent", draws, shared] => Ok(ExclusionPolicy::Recent {
+                draws: draws.parse().map_err(|_| invalid())?,
+                shared: shared.parse().map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// 생성에서 제외할 본번호 조합
+///
+/// 정확히 일치하는 조합 목록과, "이 중 하나를 부분집합으로 가지면 제외"할 k개 번호 조합 목록으로 나뉩니다.
+/// 과거 회차와 k개 이상 겹친다는 것은 그 회차의 k개 부분집합 중 하나를 포함한다는 뜻입니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Exclusion {
+    combos: HashSet<Vec<u8>>,
+    subsets: HashSet<Vec<u8>>,
+    subset_size: usize,
+}
+
+impl Exclusion {
+    /// 제외 없음
+    pub fn none() -> Exclusion {
+        Exclusion::default()
+    }
+
+    /// 정렬된 조합이 제외 대상인지 여부
+    pub fn excludes(&self, combo: &[u8]) -> bool {
+        if self.combos.contains(combo) {
+            return true;
+        }
+        if self.subsets.is_empty() || combo.len() < self.subset_size {
+            return false;
+        }
+
+        let mut found = false;
+        for_each_combination(combo, self.subset_size, |subset| {
+            found = found || self.subsets.contains(subset);
+        });
+        found
+    }
+
+    /// 제외 대상이 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty() && self.subsets.is_empty()
+    }
+
+    // 필수 번호를 모두 포함하고 나머지가 candidates 안에 있는 제외 조합 수 (부분집합 기준 제외가 있으면 미리 알 수 없음)
+    pub(crate) fn count_in(&self, game: &GameSpec, required: &[u8], candidates: &[u8]) -> Option<u64> {
+        if !self.subsets.is_empty() {
+            return None;
+        }
+
+        let count = self
+            .combos
+            .iter()
+            .filter(|combo| combo.len() == game.pick as usize)
+            .filter(|combo| combo.iter().all(|n| required.contains(n) || candidates.contains(n)))
+            .filter(|combo| required.iter().all(|r| combo.contains(r)))
+            .count();
+        Some(count as u64)
+    }
+}
+
+impl From<HashSet<Vec<u8>>> for Exclusion {
+    fn from(combos: HashSet<Vec<u8>>) -> Exclusion {
+        Exclusion {
+            combos,
+            ..Exclusion::default()
+        }
+    }
+}
+
+impl GameSpec {
+    /// 정책에 따른 제외 조합
+    pub fn exclusion<D: DrawResult>(&self, policy: &ExclusionPolicy, draws: &[D]) -> Result<Exclusion, LotteryError> {
+        let exclusion = match *policy {
+            ExclusionPolicy::None => Exclusion::none(),
+            ExclusionPolicy::FirstPlace => Exclusion::from(
+                draws
+                    .iter()
+                    .map(|draw| {
+                        let mut numbers = draw.main_numbers().to_vec();
+                        numbers.sort();
+                        numbers
+                    })
+                    .collect::<HashSet<Vec<u8>>>(),
+            ),
+            ExclusionPolicy::SecondPlace => Exclusion::from(self.used_combinations(draws)),
+            ExclusionPolicy::ThirdPlace => {
+                self.shared_exclusion(draws.iter(), self.pick as usize - 1)
+            }
+            ExclusionPolicy::Recent { draws: recent, shared } => {
+                if shared == 0 || shared > self.pick {
+                    return Err(LotteryError::InvalidInput(format!(
+                        "겹치는 번호 수는 1-{}개여야 합니다. ({}개)",
+                        self.pick, shared
+                    )));
+                }
+
+                let mut latest: Vec<&D> = draws.iter().collect();
+                latest.sort_by_key(|draw| std::cmp::Reverse(draw.round()));
+                latest.truncate(recent);
+                self.shared_exclusion(latest.into_iter(), shared as usize)
+            }
+        };
+
+        Ok(exclusion)
+    }
+
+    /// 회차순 draws의 마지막 회차를 이미 만든 제외 조합에 추가
+    ///
+    /// exclusion은 마지막 회차를 뺀 draws로 같은 정책에 따라 만든 것이어야 하며,
+    /// 최근 N회 기준(Recent)은 구간이 밀리므로 다시 계산합니다.
+    pub fn extend_exclusion<D: DrawResult>(
+        &self,
+        exclusion: &mut Exclusion,
+        policy: &ExclusionPolicy,
+        draws: &[D],
+    ) -> Result<(), LotteryError> {
+        let Some(latest) = draws.last() else {
+            return Ok(());
+        };
+
+        match *policy {
+            ExclusionPolicy::None => {}
+            ExclusionPolicy::FirstPlace => {
+                let mut numbers = latest.main_numbers().to_vec();
+                numbers.sort();
+                exclusion.combos.insert(numbers);
+            }
+            ExclusionPolicy::SecondPlace => {
+                exclusion.combos.extend(self.used_combinations(std::slice::from_ref(latest)));
+            }
+            ExclusionPolicy::ThirdPlace => {
+                let added = self.shared_exclusion(std::iter::once(latest), self.pick as usize - 1);
+                exclusion.subsets.extend(added.subsets);
+                exclusion.subset_size = added.subset_size;
+            }
+            ExclusionPolicy::Recent { .. } => {
+                *exclusion = self.exclusion(policy, draws)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // draws 중 한 회차와 본번호가 size개 이상 겹치는 조합
+    fn shared_exclusion<'a, D: DrawResult + 'a>(&self, draws: impl Iterator<Item = &'a D>, size: usize) -> Exclusion {
+        let mut subsets = HashSet::new();
+        for draw in draws {
+            let mut numbers = draw.main_numbers().to_vec();
+            numbers.sort();
+            for_each_combination(&numbers, size, |subset| {
+                subsets.insert(subset.to_vec());
+            });
+        }
+
+        Exclusion {
+            combos: HashSet::new(),
+            subsets,
+            subset_size: size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LotteryDrawing;
+
+    #[test]
+    fn extended_exclusion_matches_rebuilt_one() {
+        let draws = [
+            LotteryDrawing::new(1, [10, 23, 29, 33, 37, 40], 16).unwrap(),
+            LotteryDrawing::new(2, [9, 13, 21, 25, 32, 42], 2).unwrap(),
+            LotteryDrawing::new(3, [11, 16, 19, 21, 27, 31], 30).unwrap(),
+        ];
+        let game = GameSpec::lotto_645();
+        let policies = [
+            ExclusionPolicy::None,
+            ExclusionPolicy::FirstPlace,
+            ExclusionPolicy::SecondPlace,
+            ExclusionPolicy::ThirdPlace,
+            ExclusionPolicy::Recent { draws: 2, shared: 3 },
+        ];
+
+        for policy in policies {
+            let mut exclusion = game.exclusion(&policy, &draws[..0]).unwrap();
+            for end in 1..=draws.len() {
+                game.extend_exclusion(&mut exclusion, &policy, &draws[..end]).unwrap();
+                assert_eq!(exclusion, game.exclusion(&policy, &draws[..end]).unwrap(), "{} {}", policy, end);
+            }
+        }
+    }
+}
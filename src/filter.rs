@@ -0,0 +1,312 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::LotteryError;
+
+/// 생성된 본번호 조합을 거르는 조건 (numbers는 오름차순 정렬)
+pub trait Filter {
+    /// 화면에 표시할 조건 설명
+    fn name(&self) -> String;
+
+    /// 조합이 조건을 통과하는지 여부
+    fn accepts(&self, numbers: &[u8]) -> bool;
+}
+
+/// 번호 합계 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SumRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Default for SumRange {
+    fn default() -> SumRange {
+        SumRange { min: 100, max: 175 }
+    }
+}
+
+impl Filter for SumRange {
+    fn name(&self) -> String {
+        format!("합계 {}-{}", self.min, self.max)
+    }
+
+    fn accepts(&self, numbers: &[u8]) -> bool {
+        let sum: u32 = numbers.iter().map(|&n| n as u32).sum();
+        (self.min..=self.max).contains(&sum)
+    }
+}
+
+/// 홀수 개수 범위 (기본 2-4개 = 홀짝 2:4, 3:3, 4:2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OddCount {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Default for OddCount {
+    fn default() -> OddCount {
+        OddCount { min: 2, max: 4 }
+    }
+}
+
+impl Filter for OddCount {
+    fn name(&self) -> String {
+        format!("홀수 {}-{}개", self.min, self.max)
+    }
+
+    fn accepts(&self, numbers: &[u8]) -> bool {
+        let odd = numbers.iter().filter(|&&n| n % 2 == 1).count();
+        (self.min..=self.max).contains(&odd)
+    }
+}
+
+/// threshold 이하 저번호 개수 범위 (기본 22 이하 2-4개)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LowCount {
+    pub threshold: u8,
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Default for LowCount {
+    fn default() -> LowCount {
+        LowCount { threshold: 22, min: 2, max: 4 }
+    }
+}
+
+impl Filter for LowCount {
+    fn name(&self) -> String {
+        format!("{} 이하 {}-{}개", self.threshold, self.min, self.max)
+    }
+
+    fn accepts(&self, numbers: &[u8]) -> bool {
+        let low = numbers.iter().filter(|&&n| n <= self.threshold).count();
+        (self.min..=self.max).contains(&low)
+    }
+}
+
+/// 연속 번호 쌍 최대 개수 (예: 7, 8, 9는 2쌍)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Consecutive {
+    pub max: usize,
+}
+
+impl Default for Consecutive {
+    fn default() -> Consecutive {
+        Consecutive { max: 1 }
+    }
+}
+
+impl Filter for Consecutive {
+    fn name(&self) -> String {
+        format!("연속 쌍 {}개 이하", self.max)
+    }
+
+    fn accepts(&self, numbers: &[u8]) -> bool {
+        consecutive_pairs(numbers) <= self.max
+    }
+}
+
+/// 직전 회차 1등 번호와 겹치는 개수 상한
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreviousOverlap {
+    pub previous: Vec<u8>,
+    pub max: usize,
+}
+
+impl Filter for PreviousOverlap {
+    fn name(&self) -> String {
+        format!("직전 회차와 {}개 이하 겹침", self.max)
+    }
+
+    fn accepts(&self, numbers: &[u8]) -> bool {
+        numbers.iter().filter(|n| self.previous.contains(n)).count() <= self.max
+    }
+}
+
+/// 모든 조건을 통과해야 하는 필터 묶음
+#[derive(Default)]
+pub struct FilterSet {
+    filters: Vec<Box<dyn Filter>>,
+}
+
+impl FilterSet {
+    pub fn new() -> FilterSet {
+        FilterSet::default()
+    }
+
+    /// 조건 추가
+    pub fn with(mut self, filter: impl Filter + 'static) -> FilterSet {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    pub fn push(&mut self, filter: Box<dyn Filter>) {
+        self.filters.push(filter);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.filters.len()
+    }
+}
+
+impl Filter for FilterSet {
+    fn name(&self) -> String {
+        if self.filters.is_empty() {
+            return "필터 없음".to_string();
+        }
+
+        self.filters
+            .iter()
+            .map(|filter| filter.name())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    fn accepts(&self, numbers: &[u8]) -> bool {
+        self.filters.iter().all(|filter| filter.accepts(numbers))
+    }
+}
+
+/// 기본 제공 필터 설정 (None이면 해당 조건 미사용, 기본값은 모든 조건 사용)
+///
+/// WASM에서는 `{ sum: { min, max }, odd: null, ... }`처럼 넘기며, 생략한 항목은 기본값, null은 미사용입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FilterConfig {
+    pub sum: Option<SumRange>,
+    pub odd: Option<OddCount>,
+    pub low: Option<LowCount>,
+    pub consecutive: Option<Consecutive>,
+    /// 직전 회차와 겹치는 번호 개수 상한
+    pub previous_overlap: Option<usize>,
+}
+
+impl Default for FilterConfig {
+    fn default() -> FilterConfig {
+        FilterConfig {
+            sum: Some(SumRange::default()),
+            odd: Some(OddCount::default()),
+            low: Some(LowCount::default()),
+            consecutive: Some(Consecutive::default()),
+            previous_overlap: Some(1),
+        }
+    }
+}
+
+impl FilterConfig {
+    /// 모든 조건 미사용
+    pub fn none() -> FilterConfig {
+        FilterConfig {
+            sum: None,
+            odd: None,
+            low: None,
+            consecutive: None,
+            previous_overlap: None,
+        }
+    }
+
+    /// 필터 묶음 생성 (previous: 직전 회차 1등 번호, 없으면 겹침 조건 생략)
+    pub fn build(&self, previous: Option<&[u8]>) -> FilterSet {
+        let mut filters = FilterSet::new();
+
+        if let Some(sum) = self.sum {
+            filters.push(Box::new(sum));
+        }
+        if let Some(odd) = self.odd {
+            filters.push(Box::new(odd));
+        }
+        if let Some(low) = self.low {
+            filters.push(Box::new(low));
+        }
+        if let Some(consecutive) = self.consecutive {
+            filters.push(Box::new(consecutive));
+        }
+        if let (Some(max), Some(previous)) = (self.previous_overlap, previous) {
+            filters.push(Box::new(PreviousOverlap { previous: previous.to_vec(), max }));
+        }
+
+        filters
+    }
+}
+
+/// "default" 또는 "sum=100-175,odd=2-4,low=2-4,low-threshold=22,consecutive=1,overlap=1"
+/// (지정한 조건만 사용, 값을 생략하면 해당 조건의 기본값)
+impl FromStr for FilterConfig {
+    type Err = LotteryError;
+
+    fn from_str(s: &str) -> Result<FilterConfig, LotteryError> {
+        let s = s.trim();
+        if s == "default" {
+            return Ok(FilterConfig::default());
+        }
+        if s == "none" {
+            return Ok(FilterConfig::none());
+        }
+
+        let invalid = |part: &str| LotteryError::InvalidInput(format!("필터 '{}'", part));
+        let range = |part: &str, value: &str| -> Result<(u32, u32), LotteryError> {
+            let (min, max) = value.split_once('-').unwrap_or((value, value));
+            match (min.parse(), max.parse()) {
+                (Ok(min), Ok(max)) if min <= max => Ok((min, max)),
+                _ => Err(invalid(part)),
+            }
+        };
+
+        let mut config = FilterConfig::none();
+        for part in s.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let (key, value) = match part.split_once('=') {
+                Some((key, value)) => (key.trim(), Some(value.trim())),
+                None => (part, None),
+            };
+
+            match (key, value) {
+                ("sum", None) => config.sum = Some(SumRange::default()),
+                ("sum", Some(value)) => {
+                    let (min, max) = range(part, value)?;
+                    config.sum = Some(SumRange { min, max });
+                }
+                ("odd", None) => config.odd = Some(OddCount::default()),
+                ("odd", Some(value)) => {
+                    let (min, max) = range(part, value)?;
+                    config.odd = Some(OddCount { min: min as usize, max: max as usize });
+                }
+                ("low", None) => config.low = Some(config.low.unwrap_or_default()),
+                ("low", Some(value)) => {
+                    let (min, max) = range(part, value)?;
+                    let threshold = config.low.unwrap_or_default().threshold;
+                    config.low = Some(LowCount { threshold, min: min as usize, max: max as usize });
+                }
+                ("low-threshold", Some(value)) => {
+                    let threshold = value.parse().map_err(|_| invalid(part))?;
+                    config.low = Some(LowCount { threshold, ..config.low.unwrap_or_default() });
+                }
+                ("consecutive", None) => config.consecutive = Some(Consecutive::default()),
+                ("consecutive", Some(value)) => {
+                    let max = value.parse().map_err(|_| invalid(part))?;
+                    config.consecutive = Some(Consecutive { max });
+                }
+                ("overlap", None) => config.previous_overlap = Some(1),
+                ("overlap", Some(value)) => {
+                    config.previous_overlap = Some(value.parse().map_err(|_| invalid(part))?);
+                }
+                _ => return Err(invalid(part)),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// 정렬된 조합의 연속 번호 쌍 개수
+pub fn consecutive_pairs(numbers: &[u8]) -> usize {
+    numbers.windows(2).filter(|pair| pair[1] == pair[0] + 1).count()
+}
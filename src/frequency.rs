@@ -0,0 +1,245 @@
+//! 구간별 번호 빈도
+//!
+//! 본번호, 보너스, 합계(본번호 + 같은 추첨기 보너스)를 따로 셉니다.
+//! 한 회차에서 번호가 나올 확률은 본번호 pick/pool, 보너스 bonus/pool, 합계 (pick + bonus)/pool이므로
+//! 기대 횟수 = 회차 수 × 확률, z = (횟수 - 기대) / √(회차 수 × 확률 × (1 - 확률))입니다.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{BonusRule, DrawResult, GameSpec, LotteryError};
+
+/// 빈도를 셀 회차 구간
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum RoundWindow {
+    /// 전체 회차
+    #[default]
+    All,
+    /// 최근 rounds회
+    Last { rounds: usize },
+    /// from회 ~ to회 (양 끝 포함)
+    Range { from: u32, to: u32 },
+}
+
+impl fmt::Display for RoundWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundWindow::All => write!(f, "전체 회차"),
+            RoundWindow::Last { rounds } => write!(f, "최근 {}회", rounds),
+            RoundWindow::Range { from, to } => write!(f, "{}회 ~ {}회", from, to),
+        }
+    }
+}
+
+impl RoundWindow {
+    // 구간 안 회차 (회차순, 비어 있으면 오류)
+    pub(crate) fn select<'a, D: DrawResult>(&self, draws: &'a [D]) -> Result<Vec<&'a D>, LotteryError> {
+        let mut window: Vec<&D> = match *self {
+            RoundWindow::All => draws.iter().collect(),
+            RoundWindow::Last { rounds } => {
+                let mut latest: Vec<&D> = draws.iter().collect();
+                latest.sort_by_key(|draw| std::cmp::Reverse(draw.round()));
+                latest.truncate(rounds);
+                latest
+            }
+            RoundWindow::Range { from, to } => {
+                if from > to {
+                    return Err(LotteryError::InvalidInput(format!("분석 구간 {}회 ~ {}회", from, to)));
+                }
+                draws.iter().filter(|draw| (from..=to).contains(&draw.round())).collect()
+            }
+        };
+        window.sort_by_key(|draw| draw.round());
+
+        if window.is_empty() {
+            return Err(LotteryError::InvalidInput(format!("{}에 해당하는 회차가 없습니다.", self)));
+        }
+        Ok(window)
+    }
+}
+
+/// "all", "100" (최근 100회), "900-1000" (회차 범위)
+impl FromStr for RoundWindow {
+    type Err = LotteryError;
+
+    fn from_str(s: &str) -> Result<RoundWindow, LotteryError> {
+        let s = s.trim();
+        let invalid = || LotteryError::InvalidInput(format!("분석 구간 '{}'", s));
+
+        if s == "all" {
+            return Ok(RoundWindow::All);
+        }
+        match s.split_once('-') {
+            Some((from, to)) => Ok(RoundWindow::Range {
+                from: from.trim().parse().map_err(|_| invalid())?,
+                to: to.trim().parse().map_err(|_| invalid())?,
+            }),
+            None => Ok(RoundWindow::Last {
+                rounds: s.parse().map_err(|_| invalid())?,
+            }),
+        }
+    }
+}
+
+/// 정렬 기준 횟수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FrequencyKind {
+    Main,
+    Bonus,
+    #[default]
+    Combined,
+}
+
+/// 정렬 방향
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SortOrder {
+    /// 적은 순
+    #[default]
+    Ascending,
+    /// 많은 순
+    Descending,
+}
+
+/// 빈도 분석 조건 (기본값은 전체 회차, 합계 적은 순)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FrequencyQuery {
+    pub window: RoundWindow,
+    pub sort_by: FrequencyKind,
+    pub order: SortOrder,
+}
+
+/// 출현 횟수와 기대 횟수 비교
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountStats {
+    pub count: usize,
+    pub expected: f64,
+    /// count - expected
+    pub deviation: f64,
+    /// 표준편차 단위 편차 (기대 횟수가 0이면 0)
+    pub z_score: f64,
+}
+
+impl CountStats {
+    pub(crate) fn new(count: usize, draws: usize, probability: f64) -> CountStats {
+        let expected = draws as f64 * probability;
+        let variance = expected * (1.0 - probability);
+        let deviation = count as f64 - expected;
+
+        CountStats {
+            count,
+            expected,
+            deviation,
+            z_score: if variance > 0.0 { deviation / variance.sqrt() } else { 0.0 },
+        }
+    }
+}
+
+/// 번호 하나의 구간 빈도
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NumberFrequency {
+    pub number: u8,
+    pub main: CountStats,
+    /// 같은 추첨기 보너스로 나온 횟수 (별도 추첨기 게임은 항상 0)
+    pub bonus: CountStats,
+    pub combined: CountStats,
+}
+
+impl NumberFrequency {
+    pub fn stats(&self, kind: FrequencyKind) -> &CountStats {
+        match kind {
+            FrequencyKind::Main => &self.main,
+            FrequencyKind::Bonus => &self.bonus,
+            FrequencyKind::Combined => &self.combined,
+        }
+    }
+}
+
+/// 구간 빈도 분석 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrequencyReport {
+    pub query: FrequencyQuery,
+    /// 구간에 실제로 포함된 첫 회차와 마지막 회차
+    pub from_round: u32,
+    pub to_round: u32,
+    pub draws: usize,
+    /// query 기준 정렬 (같으면 번호순)
+    pub numbers: Vec<NumberFrequency>,
+}
+
+impl FrequencyReport {
+    /// 번호 하나의 결과
+    pub fn number(&self, number: u8) -> Option<&NumberFrequency> {
+        self.numbers.iter().find(|frequency| frequency.number == number)
+    }
+}
+
+impl GameSpec {
+    /// 구간 안 회차들의 번호별 본번호/보너스/합계 빈도
+    pub fn frequency_report<D: DrawResult>(&self, draws: &[D], query: &FrequencyQuery) -> Result<FrequencyReport, LotteryError> {
+        let window = query.window.select(draws)?;
+
+        let size = self.pool as usize + 1;
+        let mut main = vec![0usize; size];
+        let mut bonus = vec![0usize; size];
+        let same_drum_bonus = matches!(self.bonus, BonusRule::SameDrum { .. });
+
+        for draw in &window {
+            for &num in draw.main_numbers() {
+                if self.main_range().contains(&num) {
+                    main[num as usize] += 1;
+                }
+            }
+            if same_drum_bonus {
+                for &num in draw.bonus_numbers() {
+                    if self.main_range().contains(&num) {
+                        bonus[num as usize] += 1;
+                    }
+                }
+            }
+        }
+
+        let pool = self.pool as f64;
+        let bonus_count = if same_drum_bonus { self.bonus_count() as f64 } else { 0.0 };
+        let main_probability = self.pick as f64 / pool;
+        let bonus_probability = bonus_count / pool;
+
+        let draws = window.len();
+        let mut numbers: Vec<NumberFrequency> = self
+            .main_range()
+            .map(|num| {
+                let n = num as usize;
+                NumberFrequency {
+                    number: num,
+                    main: CountStats::new(main[n], draws, main_probability),
+                    bonus: CountStats::new(bonus[n], draws, bonus_probability),
+                    combined: CountStats::new(main[n] + bonus[n], draws, main_probability + bonus_probability),
+                }
+            })
+            .collect();
+
+        numbers.sort_by(|a, b| {
+            let (a_count, b_count) = (a.stats(query.sort_by).count, b.stats(query.sort_by).count);
+            let by_count = match query.order {
+                SortOrder::Ascending => a_count.cmp(&b_count),
+                SortOrder::Descending => b_count.cmp(&a_count),
+            };
+            by_count.then(a.number.cmp(&b.number))
+        });
+
+        Ok(FrequencyReport {
+            query: *query,
+            from_round: window[0].round(),
+            to_round: window[window.len() - 1].round(),
+            draws,
+            numbers,
+        })
+    }
+}
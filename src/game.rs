@@ -0,0 +1,719 @@
+use rand::seq::index::sample;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::combinadic::{rank_combination, unrank_combination};
+use crate::{Exclusion, Filter, LotteryDrawing, LotteryError, NumberConstraints};
+
+/// 보너스 번호 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum BonusRule {
+    /// 보너스 번호 없음
+    None,
+    /// 본번호와 같은 추첨기에서 추가로 뽑음 (한국 로또 2등)
+    SameDrum { count: u8 },
+    /// 별도 추첨기에서 뽑음 (Powerball, EuroMillions)
+    SeparateDrum { pool: u8, count: u8 },
+}
+
+/// 게임 정의 (번호 범위, 선택 개수, 보너스 규칙)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameSpec {
+    pub name: String,
+    /// 본번호 범위 (1..=pool)
+    pub pool: u8,
+    /// 본번호 선택 개수
+    pub pick: u8,
+    pub bonus: BonusRule,
+}
+
+/// 게임 정의와 무관한 티켓 (본번호 + 별도 추첨기 보너스)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Ticket {
+    pub numbers: Vec<u8>,
+    pub bonus: Vec<u8>,
+}
+
+/// 임의 게임의 회차 기록
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameDrawing {
+    pub round: u32,
+    pub numbers: Vec<u8>,
+    pub bonus: Vec<u8>,
+}
+
+/// GameSpec 함수들이 읽는 회차 결과
+pub trait DrawResult {
+    fn round(&self) -> u32;
+    fn main_numbers(&self) -> &[u8];
+    fn bonus_numbers(&self) -> &[u8];
+}
+
+impl DrawResult for LotteryDrawing {
+    fn round(&self) -> u32 {
+        self.round
+    }
+
+    fn main_numbers(&self) -> &[u8] {
+        &self.numbers
+    }
+
+    fn bonus_numbers(&self) -> &[u8] {
+        std::slice::from_ref(&self.bonus)
+    }
+}
+
+impl DrawResult for GameDrawing {
+    fn round(&self) -> u32 {
+        self.round
+    }
+
+    fn main_numbers(&self) -> &[u8] {
+        &self.numbers
+    }
+
+    fn bonus_numbers(&self) -> &[u8] {
+        &self.bonus
+    }
+}
+
+impl From<&LotteryDrawing> for GameDrawing {
+    fn from(drawing: &LotteryDrawing) -> GameDrawing {
+        GameDrawing {
+            round: drawing.round,
+            numbers: drawing.numbers.to_vec(),
+            bonus: vec![drawing.bonus],
+        }
+    }
+}
+
+impl Default for GameSpec {
+    fn default() -> GameSpec {
+        GameSpec::lotto_645()
+    }
+}
+
+impl GameSpec {
+    /// 한국 로또 6/45 (보너스 1개, 같은 추첨기)
+    pub fn lotto_645() -> GameSpec {
+        GameSpec {
+            name: "Lotto 6/45".to_string(),
+            pool: 45,
+            pick: 6,
+            bonus: BonusRule::SameDrum { count: 1 },
+        }
+    }
+
+    /// 로또 6/49 (보너스 1개, 같은 추첨기)
+    pub fn lotto_649() -> GameSpec {
+        GameSpec {
+            name: "Lotto 6/49".to_string(),
+            pool: 49,
+            pick: 6,
+            bonus: BonusRule::SameDrum { count: 1 },
+        }
+    }
+
+    /// Powerball (5/69 + 1/26)
+    ///
+    /// `LotteryEngine`(`withGame`)은 이 게임을 받지 않으므로 `GameDrawing`과 `GameSpec`의 함수를 직접 사용합니다.
+    pub fn powerball() -> GameSpec {
+        GameSpec {
+            name: "Powerball".to_string(),
+            pool: 69,
+            pick: 5,
+            bonus: BonusRule::SeparateDrum { pool: 26, count: 1 },
+        }
+    }
+
+    /// EuroMillions (5/50 + 2/12)
+    ///
+    /// `LotteryEngine`(`withGame`)은 이 게임을 받지 않으므로 `GameDrawing`과 `GameSpec`의 함수를 직접 사용합니다.
+    pub fn euromillions() -> GameSpec {
+        GameSpec {
+            name: "EuroMillions".to_string(),
+            pool: 50,
+            pick: 5,
+            bonus: BonusRule::SeparateDrum { pool: 12, count: 2 },
+        }
+    }
+
+    /// 이름으로 기본 제공 게임 조회 ("6/45", "6/49", "powerball", "euromillions")
+    pub fn preset(name: &str) -> Option<GameSpec> {
+        match name.to_lowercase().as_str() {
+            "6/45" | "lotto645" | "lotto 6/45" => Some(GameSpec::lotto_645()),
+            "6/49" | "lotto649" | "lotto 6/49" => Some(GameSpec::lotto_649()),
+            "powerball" => Some(GameSpec::powerball()),
+            "euromillions" => Some(GameSpec::euromillions()),
+            _ => None,
+        }
+    }
+
+    /// 본번호 범위
+    pub fn main_range(&self) -> RangeInclusive<u8> {
+        1..=self.pool
+    }
+
+    /// 추첨 결과의 보너스 번호 개수
+    pub fn bonus_count(&self) -> u8 {
+        match self.bonus {
+            BonusRule::None => 0,
+            BonusRule::SameDrum { count } | BonusRule::SeparateDrum { count, .. } => count,
+        }
+    }
+
+    /// 티켓에서 직접 고르는 보너스 번호 범위 (별도 추첨기만 해당)
+    pub fn ticket_bonus_range(&self) -> Option<RangeInclusive<u8>> {
+        match self.bonus {
+            BonusRule::SeparateDrum { pool, .. } => Some(1..=pool),
+            _ => None,
+        }
+    }
+
+    /// 가능한 티켓 수 (본번호 조합 × 별도 보너스 조합)
+    pub fn combinations(&self) -> u64 {
+        let main = binomial(self.pool as u64, self.pick as u64);
+        match self.bonus {
+            BonusRule::SeparateDrum { pool, count } => main * binomial(pool as u64, count as u64),
+            _ => main,
+        }
+    }
+
+    /// 본번호 검사 (개수, 범위, 중복)
+    pub fn check_numbers(&self, numbers: &[u8]) -> Result<(), LotteryError> {
+        if numbers.len() != self.pick as usize {
+            return Err(LotteryError::InvalidNumberCount {
+                expected: self.pick as usize,
+                found: numbers.len(),
+            });
+        }
+
+        check_distinct_in(numbers, self.main_range())
+    }
+
+    /// 회차 결과 검사 (본번호 + 보너스 규칙)
+    pub fn check_draw<D: DrawResult>(&self, draw: &D) -> Result<(), LotteryError> {
+        let numbers = draw.main_numbers();
+        let bonus = draw.bonus_numbers();
+
+        self.check_numbers(numbers)?;
+
+        match self.bonus {
+            BonusRule::None => {}
+            BonusRule::SameDrum { .. } => {
+                check_distinct_in(bonus, self.main_range())?;
+                if let Some(&collision) = bonus.iter().find(|b| numbers.contains(b)) {
+                    return Err(LotteryError::BonusCollision(collision));
+                }
+            }
+            BonusRule::SeparateDrum { pool, .. } => {
+                check_distinct_in(bonus, 1..=pool)?;
+            }
+        }
+
+        if bonus.len() != self.bonus_count() as usize {
+            return Err(LotteryError::InvalidNumberCount {
+                expected: self.bonus_count() as usize,
+                found: bonus.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// 필수 번호 검사 (개수, 범위, 중복)
+    pub fn check_required(&self, required: &[u8]) -> Result<(), LotteryError> {
+        if required.len() > self.pick as usize {
+            return Err(LotteryError::RequiredCountOutOfRange { max: self.pick });
+        }
+
+        check_distinct_in(required, self.main_range())
+    }
+
+    /// 기존 1등 본번호 조합 + (같은 추첨기 보너스인 경우) 2등 조합, 모두 정렬된 상태
+    pub fn used_combinations<D: DrawResult>(&self, draws: &[D]) -> HashSet<Vec<u8>> {
+        let mut used_combinations = HashSet::new();
+
+        // 1등 번호 조합
+        for draw in draws {
+            let mut sorted_numbers = draw.main_numbers().to_vec();
+            sorted_numbers.sort();
+            used_combinations.insert(sorted_numbers);
+        }
+
+        // 2등 번호 조합 (1등 번호 하나를 보너스로 교체)
+        if let BonusRule::SameDrum { .. } = self.bonus {
+            for draw in draws {
+                for &bonus in draw.bonus_numbers() {
+                    for i in 0..draw.main_numbers().len() {
+                        let mut second_place_combo = draw.main_numbers().to_vec();
+                        second_place_combo[i] = bonus;
+                        second_place_combo.sort();
+                        used_combinations.insert(second_place_combo);
+                    }
+                }
+            }
+        }
+
+        used_combinations
+    }
+
+    /// 필수 번호를 포함하면서 제외 조합에 없는 본번호 조합의 정확한 개수
+    pub fn count_valid(&self, exclusion: &Exclusion, required: &[u8]) -> Result<u64, LotteryError> {
+        self.count_valid_constrained(exclusion, &NumberConstraints::with_required(required))
+    }
+
+    /// 번호 제약을 만족하면서 제외 조합에 없는 본번호 조합의 정확한 개수
+    pub fn count_valid_constrained(&self, exclusion: &Exclusion, constraints: &NumberConstraints) -> Result<u64, LotteryError> {
+        let remaining_numbers = self.candidate_numbers(constraints)?;
+        let required = constraints.required.as_slice();
+
+        let needed_count = self.pick as u64 - required.len() as u64;
+        let total = binomial(remaining_numbers.len() as u64, needed_count);
+
+        if let Some(excluded) = exclusion.count_in(self, required, &remaining_numbers) {
+            return Ok(total - excluded);
+        }
+
+        // 겹침 기준 제외는 후보 공간 전체를 확인
+        let mut valid = 0;
+        for_each_combination(&remaining_numbers, needed_count as usize, |additional| {
+            let mut selected = required.to_vec();
+            selected.extend_from_slice(additional);
+            selected.sort();
+
+            if !exclusion.excludes(&selected) {
+                valid += 1;
+            }
+        });
+        Ok(valid)
+    }
+
+    /// 필수 번호를 포함하고 제외 조합에 없는 티켓을 정확히 count개 균등 추출
+    ///
+    /// 유효한 조합이 count개보다 적으면 실제 유효 조합 수와 함께 InsufficientCombinations를 반환합니다.
+    /// 본번호 조합은 서로 겹치지 않으며, 별도 추첨기 보너스는 티켓마다 독립적으로 뽑습니다.
+    pub fn generate<R: Rng + ?Sized>(
+        &self,
+        exclusion: &Exclusion,
+        required: &[u8],
+        count: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Ticket>, LotteryError> {
+        self.generate_constrained(exclusion, None, &NumberConstraints::with_required(required), count, rng)
+    }
+
+    /// generate와 같되 필터를 통과한 조합 중에서만 균등 추출
+    pub fn generate_filtered<R: Rng + ?Sized>(
+        &self,
+        exclusion: &Exclusion,
+        filter: &dyn Filter,
+        required: &[u8],
+        count: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Ticket>, LotteryError> {
+        self.generate_constrained(exclusion, Some(filter), &NumberConstraints::with_required(required), count, rng)
+    }
+
+    /// 번호 제약(필수/제외 번호, 번호 풀)을 만족하는 조합 중에서 균등 추출 (필터는 선택)
+    pub fn generate_constrained<R: Rng + ?Sized>(
+        &self,
+        exclusion: &Exclusion,
+        filter: Option<&dyn Filter>,
+        constraints: &NumberConstraints,
+        count: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Ticket>, LotteryError> {
+        TicketSampler::new(self, exclusion, filter, constraints)?.sample(count, rng)
+    }
+
+    /// 본번호 출현 빈도 (같은 추첨기 보너스 포함, 낮은 빈도순)
+    pub fn frequency<D: DrawResult>(&self, draws: &[D]) -> Vec<(u8, usize)> {
+        let same_drum_bonus = matches!(self.bonus, BonusRule::SameDrum { .. });
+        let mut frequency = vec![0usize; self.pool as usize + 1];
+
+        for draw in draws {
+            let bonus: &[u8] = if same_drum_bonus { draw.bonus_numbers() } else { &[] };
+            for &num in draw.main_numbers().iter().chain(bonus) {
+                if self.main_range().contains(&num) {
+                    frequency[num as usize] += 1;
+                }
+            }
+        }
+
+        sorted_frequency(self.main_range(), &frequency)
+    }
+
+    /// 별도 추첨기 보너스 번호 출현 빈도 (낮은 빈도순, 해당 없으면 빈 목록)
+    pub fn bonus_frequency<D: DrawResult>(&self, draws: &[D]) -> Vec<(u8, usize)> {
+        let range = match self.ticket_bonus_range() {
+            Some(range) => range,
+            None => return Vec::new(),
+        };
+
+        let mut frequency = vec![0usize; *range.end() as usize + 1];
+        for draw in draws {
+            for &num in draw.bonus_numbers() {
+                if range.contains(&num) {
+                    frequency[num as usize] += 1;
+                }
+            }
+        }
+
+        sorted_frequency(range, &frequency)
+    }
+}
+
+// 이 개수 이하의 후보 공간은 전체 나열해서 추출
+const ENUMERATION_LIMIT: u64 = 50_000;
+
+// 제외 조합 수를 모를 때 통과율을 어림하기 전에 먼저 해 보는 거부 샘플링 시도 횟수
+const REJECTION_WARMUP_ATTEMPTS: u64 = 64;
+
+/// items에서 k개를 고르는 모든 조합을 사전순으로 방문
+pub(crate) fn for_each_combination<F: FnMut(&[u8])>(items: &[u8], k: usize, mut visit: F) {
+    if k > items.len() {
+        return;
+    }
+
+    let mut indices: Vec<usize> = (0..k).collect();
+    let mut combo: Vec<u8> = indices.iter().map(|&i| items[i]).collect();
+
+    loop {
+        visit(&combo);
+
+        // 뒤에서부터 증가시킬 수 있는 자리 찾기
+        let mut i = k;
+        while i > 0 && indices[i - 1] == items.len() - k + i - 1 {
+            i -= 1;
+        }
+        if i == 0 {
+            return;
+        }
+        let i = i - 1;
+
+        indices[i] += 1;
+        for j in i + 1..k {
+            indices[j] = indices[j - 1] + 1;
+        }
+        for j in i..k {
+            combo[j] = items[indices[j]];
+        }
+    }
+}
+
+pub(crate) fn check_distinct_in(numbers: &[u8], range: RangeInclusive<u8>) -> Result<(), LotteryError> {
+    for &num in numbers {
+        if !range.contains(&num) {
+            return Err(LotteryError::NumberOutOfRange { number: num, min: *range.start(), max: *range.end() });
+        }
+    }
+
+    let mut unique_check = HashSet::new();
+    for &num in numbers {
+        if !unique_check.insert(num) {
+            return Err(LotteryError::DuplicateNumber(num));
+        }
+    }
+
+    Ok(())
+}
+
+fn sorted_frequency(range: RangeInclusive<u8>, frequency: &[usize]) -> Vec<(u8, usize)> {
+    let mut freq_pairs: Vec<(u8, usize)> = range
+        .map(|num| (num, frequency[num as usize]))
+        .collect();
+
+    freq_pairs.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+    freq_pairs
+}
+
+/// 이항계수 C(n, k)
+pub fn binomial(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+
+    let k = k.min(n - k);
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+/// 같은 조건으로 여러 번 추출할 때 제약 검사, 제외 조합 수, 후보 나열을 한 번만 하는 추출기
+///
+/// sample 한 번은 generate_constrained와 같은 난수를 소비해 같은 결과를 냅니다.
+#[derive(Clone)]
+pub(crate) struct TicketSampler<'a> {
+    exclusion: &'a Exclusion,
+    filter: Option<&'a dyn Filter>,
+    required: Vec<u8>,
+    remaining_numbers: Vec<u8>,
+    needed_count: usize,
+    total: u64,
+    // 제외 조합 수 (겹침 기준 제외나 필터가 있으면 None)
+    excluded: Option<u64>,
+    // 전체 나열한 후보를 remaining_numbers 인덱스 조합의 combinadic 순위로 저장 (처음 필요할 때 채움)
+    candidates: Option<Vec<u32>>,
+    bonus_numbers: Vec<u8>,
+    bonus_pick: usize,
+}
+
+impl<'a> TicketSampler<'a> {
+    pub(crate) fn new(
+        game: &'a GameSpec,
+        exclusion: &'a Exclusion,
+        filter: Option<&'a dyn Filter>,
+        constraints: &NumberConstraints,
+    ) -> Result<TicketSampler<'a>, LotteryError> {
+        let remaining_numbers = game.candidate_numbers(constraints)?;
+        let required = constraints.required.clone();
+        let needed_count = game.pick as usize - required.len();
+
+        let total = binomial(remaining_numbers.len() as u64, needed_count as u64);
+        // 필터가 있으면 걸러지는 조합 수를 미리 알 수 없음
+        let excluded = match filter {
+            Some(_) => None,
+            None => exclusion.count_in(game, &required, &remaining_numbers),
+        };
+
+        let bonus_numbers: Vec<u8> = game.ticket_bonus_range().map(|r| r.collect()).unwrap_or_default();
+        let bonus_pick = if bonus_numbers.is_empty() { 0 } else { game.bonus_count() as usize };
+
+        Ok(TicketSampler {
+            exclusion,
+            filter,
+            required,
+            remaining_numbers,
+            needed_count,
+            total,
+            excluded,
+            candidates: None,
+            bonus_numbers,
+            bonus_pick,
+        })
+    }
+
+    fn accepts(&self, selected: &[u8]) -> bool {
+        !self.exclusion.excludes(selected) && self.filter.is_none_or(|filter| filter.accepts(selected))
+    }
+
+    fn pick_bonus<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u8> {
+        let mut bonus: Vec<u8> = self.bonus_numbers.choose_multiple(rng, self.bonus_pick).cloned().collect();
+        bonus.sort();
+        bonus
+    }
+
+    // 필수 번호 + remaining_numbers에서 고른 번호 (indices는 1부터 시작하는 인덱스)
+    fn selection(&self, indices: &[u8]) -> Vec<u8> {
+        let mut selected = self.required.clone();
+        selected.extend(indices.iter().map(|&index| self.remaining_numbers[index as usize - 1]));
+        selected.sort();
+        selected
+    }
+
+    fn enumerate(&self) -> Result<Vec<u32>, LotteryError> {
+        if self.total > u32::MAX as u64 {
+            return Err(LotteryError::InvalidInput(format!(
+                "조건을 만족하는 조합을 나열하기에는 후보 조합이 너무 많습니다. ({}개)",
+                self.total
+            )));
+        }
+
+        let capacity = self.excluded.map_or(0, |excluded| self.total - excluded);
+        let mut candidates: Vec<u32> = Vec::with_capacity(capacity as usize);
+        let indices: Vec<u8> = (1..=self.remaining_numbers.len() as u8).collect();
+        for_each_combination(&indices, self.needed_count, |picked| {
+            if self.accepts(&self.selection(picked)) {
+                candidates.push(rank_combination(picked) as u32);
+            }
+        });
+        Ok(candidates)
+    }
+
+    /// 조건을 만족하는 서로 다른 티켓 count개 균등 추출
+    pub(crate) fn sample<R: Rng + ?Sized>(&mut self, count: usize, rng: &mut R) -> Result<Vec<Ticket>, LotteryError> {
+        let total = self.total;
+        if let Some(excluded) = self.excluded {
+            let valid = total - excluded;
+            if count as u64 > valid {
+                return Err(LotteryError::InsufficientCombinations {
+                    requested: count,
+                    found: valid as usize,
+                });
+            }
+        }
+
+        // 제외 조합이 많거나 후보 공간이 작으면 전체 나열 후 비복원 추출,
+        // 그렇지 않으면 거부 샘플링 (기대 시도 횟수 2회 이하).
+        // 제외 조합 수를 미리 알 수 없으면(겹침 기준 제외, 필터) 관측한 통과율로 남은 시도 횟수를 어림해
+        // 전체 나열(total번 검사)보다 비싸질 것 같으면 전체 나열로 전환
+        let (use_rejection, bounded) = match self.excluded {
+            Some(excluded) => (
+                total > ENUMERATION_LIMIT && excluded * 2 <= total && (count as u64) * 2 <= total - excluded,
+                false,
+            ),
+            None => (total > ENUMERATION_LIMIT, true),
+        };
+
+        let mut results = Vec::with_capacity(count);
+
+        if use_rejection {
+            let mut chosen = HashSet::with_capacity(count);
+            let mut attempts = 0u64;
+
+            while results.len() < count {
+                if bounded && attempts >= REJECTION_WARMUP_ATTEMPTS {
+                    let rate = results.len() as f64 / attempts as f64;
+                    let needed = (count - results.len()) as f64;
+                    if rate == 0.0 || attempts as f64 + 2.0 * needed / rate > total as f64 {
+                        break;
+                    }
+                }
+                attempts += 1;
+
+                let mut selected = self.required.clone();
+                selected.extend(self.remaining_numbers.choose_multiple(rng, self.needed_count).cloned());
+                selected.sort();
+
+                let bonus = self.pick_bonus(rng);
+
+                if self.accepts(&selected) && chosen.insert(selected.clone()) {
+                    results.push(Ticket { numbers: selected, bonus });
+                }
+            }
+
+            if results.len() == count {
+                return Ok(results);
+            }
+            results.clear();
+        }
+
+        let candidates = match self.candidates.take() {
+            Some(candidates) => candidates,
+            None => self.enumerate()?,
+        };
+
+        if count > candidates.len() {
+            let found = candidates.len();
+            self.candidates = Some(candidates);
+            return Err(LotteryError::InsufficientCombinations { requested: count, found });
+        }
+
+        // 후보 순서는 건드리지 않고 인덱스만 뽑아, 이전 추출과 관계없이 같은 난수면 같은 결과
+        for index in sample(rng, candidates.len(), count) {
+            let numbers = self.selection(&unrank_combination(candidates[index] as u64, self.needed_count));
+            let bonus = self.pick_bonus(rng);
+            results.push(Ticket { numbers, bonus });
+        }
+
+        self.candidates = Some(candidates);
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{seeded_rng, ExclusionPolicy, FilterConfig, SumRange};
+
+    fn draws() -> Vec<LotteryDrawing> {
+        vec![
+            LotteryDrawing::new(1, [10, 23, 29, 33, 37, 40], 16).unwrap(),
+            LotteryDrawing::new(2, [1, 4, 6, 8, 9, 11], 2).unwrap(),
+            LotteryDrawing::new(3, [2, 5, 7, 10, 12, 30], 3).unwrap(),
+        ]
+    }
+
+    // 같은 sampler로 여러 번 뽑은 티켓이 모두 조건을 만족하는지 검사
+    fn assert_valid_samples(
+        game: &GameSpec,
+        exclusion: &Exclusion,
+        filter: Option<&dyn Filter>,
+        constraints: &NumberConstraints,
+        count: usize,
+    ) {
+        let mut sampler = TicketSampler::new(game, exclusion, filter, constraints).unwrap();
+        let mut rng = seeded_rng(Some(7));
+        let range = constraints.pool.clone().unwrap_or_else(|| game.main_range().collect());
+
+        for _ in 0..3 {
+            let tickets = sampler.sample(count, &mut rng).unwrap();
+            assert_eq!(tickets.len(), count);
+
+            let distinct: HashSet<&Vec<u8>> = tickets.iter().map(|ticket| &ticket.numbers).collect();
+            assert_eq!(distinct.len(), count, "중복 티켓");
+
+            for ticket in &tickets {
+                let numbers = &ticket.numbers;
+                assert_eq!(numbers.len(), game.pick as usize);
+                assert!(numbers.windows(2).all(|pair| pair[0] < pair[1]), "정렬되지 않음: {:?}", numbers);
+                assert!(numbers.iter().all(|n| range.contains(n)), "범위 밖: {:?}", numbers);
+                assert!(numbers.iter().all(|n| !constraints.excluded.contains(n)), "제외 번호: {:?}", numbers);
+                assert!(constraints.required.iter().all(|n| numbers.contains(n)), "필수 번호 누락: {:?}", numbers);
+                assert!(!exclusion.excludes(numbers), "제외 조합: {:?}", numbers);
+                assert!(filter.is_none_or(|filter| filter.accepts(numbers)), "필터 미통과: {:?}", numbers);
+                match game.ticket_bonus_range() {
+                    Some(bonus_range) => {
+                        assert_eq!(ticket.bonus.len(), game.bonus_count() as usize);
+                        assert!(ticket.bonus.iter().all(|n| bonus_range.contains(n)), "보너스 범위 밖: {:?}", ticket.bonus);
+                    }
+                    None => assert!(ticket.bonus.is_empty()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sampled_tickets_satisfy_constraints() {
+        let lotto = GameSpec::lotto_645();
+        let draws = draws();
+        let second = lotto.exclusion(&ExclusionPolicy::SecondPlace, &draws).unwrap();
+        let third = lotto.exclusion(&ExclusionPolicy::ThirdPlace, &draws).unwrap();
+        let filters = FilterConfig::default().build(None);
+        let low_sum = SumRange { min: 21, max: 45 };
+        let any = NumberConstraints::default();
+        let small = NumberConstraints { required: vec![1, 2], excluded: vec![3], pool: Some((1..=10).collect()) };
+
+        // 거부 샘플링 (제외 조합 수를 아는 경우 / 모르는 경우)
+        assert_valid_samples(&lotto, &second, None, &NumberConstraints::with_required(&[7, 23]), 5);
+        assert_valid_samples(&lotto, &third, None, &any, 5);
+        assert_valid_samples(&lotto, &Exclusion::none(), Some(&filters), &any, 5);
+        // 전체 나열 (후보 공간이 작은 경우 / 통과율이 낮아 거부 샘플링을 그만둔 경우)
+        assert_valid_samples(&lotto, &second, None, &small, 10);
+        assert_valid_samples(&lotto, &third, Some(&low_sum), &NumberConstraints::with_required(&[1, 2]), 5);
+        // 별도 추첨기 보너스
+        assert_valid_samples(&GameSpec::powerball(), &Exclusion::none(), None, &any, 5);
+    }
+
+    #[test]
+    fn sampler_matches_generate_constrained_and_reports_shortage() {
+        let lotto = GameSpec::lotto_645();
+        let exclusion = lotto.exclusion(&ExclusionPolicy::ThirdPlace, &draws()).unwrap();
+        let small = NumberConstraints { required: vec![1, 2], excluded: vec![3], pool: Some((1..=10).collect()) };
+
+        // 같은 난수로 여러 번 뽑으면 generate_constrained를 매번 부른 것과 같음
+        let mut sampler = TicketSampler::new(&lotto, &exclusion, None, &small).unwrap();
+        let (mut a, mut b) = (seeded_rng(Some(3)), seeded_rng(Some(3)));
+        for _ in 0..3 {
+            assert_eq!(
+                sampler.sample(4, &mut a).unwrap(),
+                lotto.generate_constrained(&exclusion, None, &small, 4, &mut b).unwrap()
+            );
+        }
+
+        // 1, 2 + {4..10} 중 4개 = 최대 35개보다 많이 요청
+        assert!(matches!(
+            sampler.sample(36, &mut a),
+            Err(LotteryError::InsufficientCombinations { requested: 36, .. })
+        ));
+
+        // 짧은 시드 벡터 (6/45, 필수 7, 23)
+        let tickets = lotto
+            .generate_constrained(&Exclusion::none(), None, &NumberConstraints::with_required(&[7, 23]), 1, &mut seeded_rng(Some(42)))
+            .unwrap();
+        assert_eq!(tickets[0].numbers, [1, 7, 19, 22, 23, 29]);
+    }
+}
@@ -0,0 +1,163 @@
+//! 번호별 미출현 갭
+//!
+//! 갭은 연속한 두 출현 사이에 그 번호가 나오지 않은 회차 수이며(연달아 나오면 0),
+//! 현재 갭은 마지막 출현 이후 회차 수입니다(한 번도 안 나왔으면 전체 회차 수).
+//! 첫 출현 전 구간은 시작이 잘린 갭이라 분포에 넣지 않습니다.
+//! 현재 갭이 그 번호의 과거 갭 분포에서 percentile 백분위수(최근접 순위)보다 크면 과출(overdue)로 표시합니다.
+
+use serde::Serialize;
+
+use crate::{BonusRule, DrawResult, GameSpec, LotteryError};
+
+/// 본번호 또는 보너스 한 종류의 갭 통계
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GapStats {
+    /// 마지막 출현 이후 회차 수
+    pub current: usize,
+    /// 마지막 출현 회차
+    pub last_round: Option<u32>,
+    pub appearances: usize,
+    /// 과거 갭 중 최댓값 (두 번 이상 나와야 계산)
+    pub longest: Option<usize>,
+    pub average: Option<f64>,
+    /// (갭, 횟수) 갭 오름차순
+    pub distribution: Vec<(usize, usize)>,
+    /// 과거 갭의 percentile 백분위수
+    pub threshold: Option<usize>,
+    /// 현재 갭이 threshold보다 큰지 여부
+    pub overdue: bool,
+}
+
+impl GapStats {
+    // 회차순 출현 위치(0부터)로 통계 계산
+    fn new(seen: &[usize], rounds: &[u32], percentile: f64) -> GapStats {
+        let mut gaps: Vec<usize> = seen.windows(2).map(|pair| pair[1] - pair[0] - 1).collect();
+        gaps.sort();
+
+        let current = seen.last().map_or(rounds.len(), |&index| rounds.len() - 1 - index);
+        let threshold = if gaps.is_empty() {
+            None
+        } else {
+            let rank = (percentile / 100.0 * gaps.len() as f64).ceil().max(1.0) as usize;
+            Some(gaps[rank.min(gaps.len()) - 1])
+        };
+
+        let mut distribution: Vec<(usize, usize)> = Vec::new();
+        for &gap in &gaps {
+            match distribution.last_mut() {
+                Some((last, count)) if *last == gap => *count += 1,
+                _ => distribution.push((gap, 1)),
+            }
+        }
+
+        GapStats {
+            current,
+            last_round: seen.last().map(|&index| rounds[index]),
+            appearances: seen.len(),
+            longest: gaps.last().copied(),
+            average: if gaps.is_empty() {
+                None
+            } else {
+                Some(gaps.iter().sum::<usize>() as f64 / gaps.len() as f64)
+            },
+            distribution,
+            threshold,
+            overdue: threshold.is_some_and(|threshold| current > threshold),
+        }
+    }
+}
+
+/// 번호 하나의 본번호/보너스 갭
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NumberGap {
+    pub number: u8,
+    pub main: GapStats,
+    /// 같은 추첨기 보너스로 나온 기준 (별도 추첨기 게임은 출현 없음)
+    pub bonus: GapStats,
+}
+
+/// 갭 분석 결과 (번호순)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GapReport {
+    pub percentile: f64,
+    pub draws: usize,
+    pub latest_round: u32,
+    pub numbers: Vec<NumberGap>,
+    /// 본번호 기준 과출 번호 (현재 갭 큰 순)
+    pub overdue_main: Vec<u8>,
+    /// 보너스 기준 과출 번호 (현재 갭 큰 순)
+    pub overdue_bonus: Vec<u8>,
+}
+
+impl GapReport {
+    /// 번호 하나의 결과
+    pub fn number(&self, number: u8) -> Option<&NumberGap> {
+        self.numbers.iter().find(|gap| gap.number == number)
+    }
+}
+
+impl GameSpec {
+    /// 번호별 현재/최장/평균 갭과 분포 (percentile: 과출 판정 백분위수, 0 초과 100 이하)
+    pub fn gap_report<D: DrawResult>(&self, draws: &[D], percentile: f64) -> Result<GapReport, LotteryError> {
+        if !(percentile > 0.0 && percentile <= 100.0) {
+            return Err(LotteryError::InvalidInput(format!("백분위수는 0 초과 100 이하여야 합니다. ({})", percentile)));
+        }
+
+        let mut sorted: Vec<&D> = draws.iter().collect();
+        sorted.sort_by_key(|draw| draw.round());
+        let Some(latest) = sorted.last() else {
+            return Err(LotteryError::InvalidInput("갭을 계산할 회차가 없습니다.".to_string()));
+        };
+        let latest_round = latest.round();
+        let rounds: Vec<u32> = sorted.iter().map(|draw| draw.round()).collect();
+
+        let size = self.pool as usize + 1;
+        let mut main_seen = vec![Vec::new(); size];
+        let mut bonus_seen = vec![Vec::new(); size];
+        let same_drum_bonus = matches!(self.bonus, BonusRule::SameDrum { .. });
+
+        for (index, draw) in sorted.iter().enumerate() {
+            for &num in draw.main_numbers() {
+                if self.main_range().contains(&num) {
+                    main_seen[num as usize].push(index);
+                }
+            }
+            if same_drum_bonus {
+                for &num in draw.bonus_numbers() {
+                    if self.main_range().contains(&num) {
+                        bonus_seen[num as usize].push(index);
+                    }
+                }
+            }
+        }
+
+        let numbers: Vec<NumberGap> = self
+            .main_range()
+            .map(|num| NumberGap {
+                number: num,
+                main: GapStats::new(&main_seen[num as usize], &rounds, percentile),
+                bonus: GapStats::new(&bonus_seen[num as usize], &rounds, percentile),
+            })
+            .collect();
+
+        let overdue = |stats: fn(&NumberGap) -> &GapStats| {
+            let mut overdue: Vec<&NumberGap> = numbers.iter().filter(|gap| stats(gap).overdue).collect();
+            overdue.sort_by(|a, b| stats(b).current.cmp(&stats(a).current).then(a.number.cmp(&b.number)));
+            overdue.into_iter().map(|gap| gap.number).collect::<Vec<u8>>()
+        };
+        let overdue_main = overdue(|gap| &gap.main);
+        let overdue_bonus = overdue(|gap| &gap.bonus);
+
+        Ok(GapReport {
+            percentile,
+            draws: rounds.len(),
+            latest_round,
+            numbers,
+            overdue_main,
+            overdue_bonus,
+        })
+    }
+}
@@ -1,18 +1,72 @@
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LotteryDrawing {
-    pub round: u32,
-    pub numbers: [u8; 6],
-    pub bonus: u8,
+
+mod backtest;
+mod combinadic;
+mod constraints;
+mod cooccurrence;
+mod covering;
+mod distribution;
+mod diversity;
+mod drawing;
+mod error;
+mod ev;
+mod exclusion;
+mod filter;
+mod frequency;
+mod game;
+mod gap;
+mod mask;
+mod odds;
+mod prize;
+mod randomness;
+mod recommend;
+mod seed;
+#[cfg(not(target_arch = "wasm32"))]
+mod simulation;
+mod wheel;
+
+pub use backtest::{backtest, BacktestConfig, BacktestReport, RequiredStrategy, Strategy, UniformStrategy};
+pub use combinadic::{rank_6_45, unrank_6_45, TOTAL_6_45};
+pub use constraints::NumberConstraints;
+pub use cooccurrence::{CooccurrenceQuery, CooccurrenceReport, PairCounts, PairFrequency, TripleFrequency, TripleSummary};
+pub use covering::{search_covering, CoveringConfig, CoveringSearch};
+pub use distribution::{ConditionCoverage, DistributionReport, Feature, FeatureBucket, FeatureDistribution, FilterCoverage};
+pub use diversity::{diverse_select, DiverseSelection, DiversityConfig, DiversityStats};
+pub use drawing::{validate, validate_with, LotteryDrawing, ValidationIssue, ValidationReport};
+pub use error::LotteryError;
+pub use ev::{expected_value, tier_probabilities, EvParams, EvReport, PrizePool, PrizeTax, RoundPrizes, TierEv};
+pub use exclusion::{Exclusion, ExclusionPolicy};
+pub use filter::{
+    consecutive_pairs, Consecutive, Filter, FilterConfig, FilterSet, LowCount, OddCount, PreviousOverlap, SumRange,
+};
+pub use frequency::{CountStats, FrequencyKind, FrequencyQuery, FrequencyReport, NumberFrequency, RoundWindow, SortOrder};
+pub use game::{binomial, BonusRule, DrawResult, GameDrawing, GameSpec, Ticket};
+pub use gap::{GapReport, GapStats, NumberGap};
+pub use mask::{TicketMask, MAX_MASK_NUMBER};
+pub use odds::{match_probabilities, ticket_odds, MatchProbability, OddsReport, TierOdds, DRAWS_PER_YEAR};
+pub use prize::{check_ticket, PrizeTable, PrizeTier, TicketCheck, TICKET_PRICE};
+pub use randomness::{ChiSquareTest, GapBin, GapTest, RandomnessReport, RunsTest, SerialCorrelationTest, SumKsTest};
+pub use recommend::{NumberScore, Recommender, RecommenderConfig, ScoredTicket};
+pub use seed::{random_seed, seeded_rng, LotteryRng};
+#[cfg(not(target_arch = "wasm32"))]
+pub use simulation::{
+    simulate, DrawSource, NetDistribution, PlayStrategy, RuinStats, SimulationConfig, SimulationReport, StreakStats, TierChance,
+};
+pub use wheel::{abbreviated_wheel, check_guarantee, full_wheel, GuaranteeCheck, Wheel, WheelGuarantee, MAX_WHEEL_POOL};
+
+/// 저장된 회차 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundRange {
+    pub min: u32,
+    pub max: u32,
+    pub count: usize,
 }
 
 #[wasm_bindgen]
 pub struct LotteryEngine {
+    game: GameSpec,
     drawings: Vec<LotteryDrawing>,
 }
 
@@ -21,83 +75,259 @@ impl LotteryEngine {
     #[wasm_bindgen(constructor)]
     pub fn new(json_data: &str) -> Result<LotteryEngine, JsValue> {
         let drawings: Vec<LotteryDrawing> = serde_json::from_str(json_data)
-            .map_err(|e| JsValue::from_str(&format!("JSON 파싱 오류: {}", e)))?;
+            .map_err(LotteryError::from)?;
+
+        Ok(LotteryEngine::from_drawings(drawings)?)
+    }
+
+    /// 게임 정의를 지정해 엔진 생성 ("6/45", "6/49")
+    ///
+    /// 6개 선택 + 같은 추첨기 보너스 1개 게임만 지원하며, "powerball", "euromillions"는 UNSUPPORTED_GAME 오류를 반환합니다.
+    #[wasm_bindgen(js_name = withGame)]
+    pub fn new_with_game(json_data: &str, game: &str) -> Result<LotteryEngine, JsValue> {
+        let game = GameSpec::preset(game)
+            .ok_or_else(|| LotteryError::UnsupportedGame(game.to_string()))?;
+        let drawings: Vec<LotteryDrawing> = serde_json::from_str(json_data)
+            .map_err(LotteryError::from)?;
 
-        Ok(LotteryEngine { drawings })
+        Ok(LotteryEngine::with_game(game, drawings)?)
     }
 
-    /// 5개 세트의 로또 번호 생성
+    /// 5개 세트의 로또 번호 생성 (seed를 주면 같은 결과 재현)
+    ///
+    /// exclusion: "none", "first", "second"(기본값), "third", "recent:N:K"
     #[wasm_bindgen(js_name = generateNumbersSets)]
-    pub fn generate_numbers_sets(&self) -> JsValue {
-        let sets = self.generate_sets(5);
-        serde_wasm_bindgen::to_value(&sets).unwrap()
+    pub fn generate_numbers_sets(&self, seed: Option<u64>, exclusion: Option<String>) -> Result<JsValue, JsValue> {
+        let policy = parse_policy(exclusion)?;
+        let sets = self.generate_sets_excluding(&policy, &[], 5, seed)?;
+        Ok(serde_wasm_bindgen::to_value(&sets).unwrap())
     }
 
-    /// 특정 번호를 포함한 5개 세트 생성
+    /// 특정 번호를 포함한 5개 세트 생성 (seed를 주면 같은 결과 재현)
     #[wasm_bindgen(js_name = generateNumbersSetsWithRequired)]
-    pub fn generate_numbers_sets_with_required(&self, required: Vec<u8>) -> Result<JsValue, JsValue> {
-        if required.is_empty() || required.len() > 6 {
-            return Err(JsValue::from_str("필수 번호는 1-6개 사이여야 합니다."));
+    pub fn generate_numbers_sets_with_required(&self, required: Vec<u8>, seed: Option<u64>, exclusion: Option<String>) -> Result<JsValue, JsValue> {
+        if required.is_empty() {
+            return Err(LotteryError::RequiredCountOutOfRange { max: self.game.pick }.into());
         }
 
-        for &num in &required {
-            if num < 1 || num > 45 {
-                return Err(JsValue::from_str("번호는 1-45 사이여야 합니다."));
-            }
-        }
+        let policy = parse_policy(exclusion)?;
+        let sets = self.generate_sets_excluding(&policy, &required, 5, seed)?;
 
-        let mut unique_check = HashSet::new();
-        for &num in &required {
-            if !unique_check.insert(num) {
-                return Err(JsValue::from_str("중복된 번호가 있습니다."));
-            }
-        }
+        Ok(serde_wasm_bindgen::to_value(&sets).unwrap())
+    }
+
+    /// 번호 제약을 만족하는 5개 세트 생성
+    ///
+    /// constraints: `{ required: [7], excluded: [4, 13, 44], pool: [1, 2, ...] }` (생략한 항목은 제약 없음)
+    #[wasm_bindgen(js_name = generateConstrainedSets)]
+    pub fn generate_constrained_sets(&self, constraints: JsValue, seed: Option<u64>, exclusion: Option<String>) -> Result<JsValue, JsValue> {
+        let constraints: NumberConstraints = from_js_or_default(constraints)?;
+        let policy = parse_policy(exclusion)?;
+        let sets = self.generate_sets_constrained(&policy, None, &constraints, 5, seed)?;
+
+        Ok(serde_wasm_bindgen::to_value(&sets).unwrap())
+    }
+
+    /// 통계 필터를 통과한 5개 세트 생성
+    ///
+    /// filters: `{ sum: { min, max }, odd: { min, max }, low: { threshold, min, max }, consecutive: { max }, previousOverlap }`
+    /// (생략하면 기본값, null이면 해당 조건 미사용)
+    #[wasm_bindgen(js_name = generateFilteredSets)]
+    pub fn generate_filtered_sets(&self, filters: JsValue, required: Option<Vec<u8>>, seed: Option<u64>, exclusion: Option<String>) -> Result<JsValue, JsValue> {
+        let config: FilterConfig = from_js_or_default(filters)?;
 
-        let sets = self.generate_sets_with_required(&required, 5)
-            .map_err(|e| JsValue::from_str(&e))?;
+        let policy = parse_policy(exclusion)?;
+        let filter = self.filters(&config);
+        let required = required.unwrap_or_default();
+        let sets = self.generate_sets_filtered(&policy, &filter, &required, 5, seed)?;
 
         Ok(serde_wasm_bindgen::to_value(&sets).unwrap())
     }
 
+    /// 점수 기반 추천 (config/filters를 생략하면 기본값)
+    ///
+    /// 반환: `[{ numbers, score }, ...]` (점수 내림차순)
+    #[wasm_bindgen(js_name = recommend)]
+    pub fn recommend_js(&self, count: usize, config: JsValue, filters: JsValue, seed: Option<u64>, exclusion: Option<String>) -> Result<JsValue, JsValue> {
+        let config: RecommenderConfig = from_js_or_default(config)?;
+        let filters: FilterConfig = from_js_or_default(filters)?;
+        let policy = parse_policy(exclusion)?;
+
+        let tickets = self.recommend(&config, &policy, &self.filters(&filters), count, seed)?;
+        Ok(serde_wasm_bindgen::to_value(&tickets).unwrap())
+    }
+
+    /// 점수 기반 추천 + 다양성 선택 (config/diversity/filters를 생략하면 기본값)
+    ///
+    /// 반환: `{ tickets: [{ numbers, score }], stats: { uniqueNumbers, numberCounts: [[번호, 횟수]] } }`
+    #[wasm_bindgen(js_name = recommendDiverse)]
+    pub fn recommend_diverse_js(&self, count: usize, config: JsValue, diversity: JsValue, filters: JsValue, seed: Option<u64>, exclusion: Option<String>) -> Result<JsValue, JsValue> {
+        let config: RecommenderConfig = from_js_or_default(config)?;
+        let diversity: DiversityConfig = from_js_or_default(diversity)?;
+        let filters: FilterConfig = from_js_or_default(filters)?;
+        let policy = parse_policy(exclusion)?;
+
+        let selection = self.recommend_diverse(&config, &diversity, &policy, &self.filters(&filters), count, seed)?;
+        Ok(serde_wasm_bindgen::to_value(&selection).unwrap())
+    }
+
+    /// 추천 점수 구성 (번호순)
+    #[wasm_bindgen(js_name = getNumberScores)]
+    pub fn get_number_scores(&self, config: JsValue) -> Result<JsValue, JsValue> {
+        let config: RecommenderConfig = from_js_or_default(config)?;
+        let recommender = self.recommender(config)?;
+        Ok(serde_wasm_bindgen::to_value(recommender.number_scores()).unwrap())
+    }
+
+    /// 휠링 티켓 (ifDrawn, matched를 생략하면 전체 휠)
+    #[wasm_bindgen(js_name = wheel)]
+    pub fn wheel_js(&self, pool: Vec<u8>, keys: Vec<u8>, if_drawn: Option<u8>, matched: Option<u8>, exclusion: Option<String>) -> Result<JsValue, JsValue> {
+        let policy = parse_policy(exclusion)?;
+        let wheel = match (if_drawn, matched) {
+            (Some(if_drawn), Some(matched)) => {
+                self.abbreviated_wheel(&pool, &keys, WheelGuarantee { if_drawn, matched }, &policy)?
+            }
+            _ => self.full_wheel(&pool, &keys, &policy)?,
+        };
+        Ok(serde_wasm_bindgen::to_value(&wheel).unwrap())
+    }
+
+    /// 보장 조건을 만족하는 더 적은 티켓 탐색 (config: { timeBudgetMs, maxIterations, seed }, 생략 시 기본값)
+    #[wasm_bindgen(js_name = searchCovering)]
+    pub fn search_covering_js(&self, pool: Vec<u8>, keys: Vec<u8>, if_drawn: u8, matched: u8, config: JsValue, exclusion: Option<String>) -> Result<JsValue, JsValue> {
+        let policy = parse_policy(exclusion)?;
+        let config: CoveringConfig = from_js_or_default(config)?;
+        let search = self.search_covering(&pool, &keys, WheelGuarantee { if_drawn, matched }, &policy, &config)?;
+        Ok(serde_wasm_bindgen::to_value(&search).unwrap())
+    }
+
+    /// 화면에 표시하고 생성에 넘길 새 시드
+    #[wasm_bindgen(js_name = randomSeed)]
+    pub fn random_seed() -> u64 {
+        random_seed()
+    }
+
+    /// 티켓의 조합 인덱스 (공유 링크/저장용)
+    #[wasm_bindgen(js_name = rankTicket)]
+    pub fn rank_ticket(&self, numbers: Vec<u8>) -> Result<u64, JsValue> {
+        Ok(self.game.rank(&numbers)?)
+    }
+
+    /// 조합 인덱스를 티켓 번호로 변환
+    #[wasm_bindgen(js_name = unrankTicket)]
+    pub fn unrank_ticket(&self, index: u64) -> Result<Vec<u8>, JsValue> {
+        Ok(self.game.unrank(index)?)
+    }
+
+    /// 여러 티켓의 당첨 결과 확인 (tickets: number[][])
+    #[wasm_bindgen(js_name = checkTickets)]
+    pub fn check_tickets_js(&self, tickets: JsValue, round: u32) -> Result<JsValue, JsValue> {
+        let tickets = tickets_from_js(tickets)?;
+        let results = self.check_tickets(&tickets, round)?;
+        Ok(serde_wasm_bindgen::to_value(&results).unwrap())
+    }
+
+    /// 티켓 1장의 세전/세후 기대값 (params를 생략하면 기본 예상치)
+    #[wasm_bindgen(js_name = expectedValue)]
+    pub fn expected_value_js(&self, params: JsValue) -> Result<JsValue, JsValue> {
+        let params: EvParams = from_js_or_default(params)?;
+
+        let report = self.expected_value(&params)?;
+        Ok(serde_wasm_bindgen::to_value(&report).unwrap())
+    }
+
+    /// 티켓 묶음의 일치 개수별 확률, 등수별 당첨 확률과 기다리는 회차 수 (tickets: number[][])
+    #[wasm_bindgen(js_name = getOdds)]
+    pub fn get_odds(&self, tickets: JsValue) -> Result<JsValue, JsValue> {
+        let tickets = tickets_from_js(tickets)?;
+        let report = self.odds(&tickets)?;
+        Ok(serde_wasm_bindgen::to_value(&report).unwrap())
+    }
+
     /// 빈도 기반 번호 추천 (낮은 빈도순)
     #[wasm_bindgen(js_name = getNumberFrequency)]
     pub fn get_number_frequency(&self) -> JsValue {
-        let frequency = self.calculate_frequency();
+        let frequency = self.number_frequency();
         serde_wasm_bindgen::to_value(&frequency).unwrap()
     }
 
+    /// 구간별 본번호/보너스/합계 빈도와 기대값 대비 편차
+    ///
+    /// query: `{ window: { kind: "last", rounds: 100 }, sortBy: "main", order: "descending" }` (생략하면 전체 회차, 합계 적은 순)
+    #[wasm_bindgen(js_name = getFrequencyReport)]
+    pub fn get_frequency_report(&self, query: JsValue) -> Result<JsValue, JsValue> {
+        let query: FrequencyQuery = from_js_or_default(query)?;
+        let report = self.frequency_report(&query)?;
+        Ok(serde_wasm_bindgen::to_value(&report).unwrap())
+    }
+
+    /// 번호 쌍(선택 시 삼중) 동시출현과 히트맵용 행렬
+    ///
+    /// query: `{ window: { kind: "last", rounds: 100 }, triples: true, top: 10 }` (생략하면 전체 회차, 쌍만, 10개)
+    #[wasm_bindgen(js_name = getCooccurrenceReport)]
+    pub fn get_cooccurrence_report(&self, query: JsValue) -> Result<JsValue, JsValue> {
+        let query: CooccurrenceQuery = from_js_or_default(query)?;
+        let report = self.cooccurrence_report(&query)?;
+        Ok(serde_wasm_bindgen::to_value(&report).unwrap())
+    }
+
+    /// 특징별 이론 분포와 실제 분포 (window 생략 시 전체 회차)
+    #[wasm_bindgen(js_name = getDistributionReport)]
+    pub fn get_distribution_report(&self, window: JsValue) -> Result<JsValue, JsValue> {
+        let window: RoundWindow = from_js_or_default(window)?;
+        let report = self.distribution_report(window)?;
+        Ok(serde_wasm_bindgen::to_value(&report).unwrap())
+    }
+
+    /// 필터 설정이 남기는 조합 비율 (config 생략 시 기본 필터, window 생략 시 전체 회차)
+    #[wasm_bindgen(js_name = getFilterCoverage)]
+    pub fn get_filter_coverage(&self, config: JsValue, window: JsValue) -> Result<JsValue, JsValue> {
+        let config: FilterConfig = from_js_or_default(config)?;
+        let window: RoundWindow = from_js_or_default(window)?;
+        let coverage = self.filter_coverage(&config, window)?;
+        Ok(serde_wasm_bindgen::to_value(&coverage).unwrap())
+    }
+
+    /// 무작위성 검정 통계량과 p값 (window: `{ kind: "last", rounds: 300 }`, 생략하면 전체 회차)
+    #[wasm_bindgen(js_name = getRandomnessReport)]
+    pub fn get_randomness_report(&self, window: JsValue) -> Result<JsValue, JsValue> {
+        let window: RoundWindow = from_js_or_default(window)?;
+        let report = self.randomness_report(window)?;
+        Ok(serde_wasm_bindgen::to_value(&report).unwrap())
+    }
+
+    /// 번호별 현재/최장/평균 갭과 분포 (percentile 생략 시 90)
+    #[wasm_bindgen(js_name = getGapReport)]
+    pub fn get_gap_report(&self, percentile: Option<f64>) -> Result<JsValue, JsValue> {
+        let report = self.gap_report(percentile.unwrap_or(90.0))?;
+        Ok(serde_wasm_bindgen::to_value(&report).unwrap())
+    }
+
     /// 새 회차 추가
     #[wasm_bindgen(js_name = addNewDrawing)]
     pub fn add_new_drawing(&mut self, round: u32, numbers: Vec<u8>, bonus: u8) -> Result<(), JsValue> {
-        if numbers.len() != 6 {
-            return Err(JsValue::from_str("6개의 번호를 입력해주세요."));
-        }
-
         let numbers_array: [u8; 6] = numbers.try_into()
-            .map_err(|_| JsValue::from_str("번호 변환 오류"))?;
+            .map_err(|numbers: Vec<u8>| LotteryError::InvalidNumberCount {
+                expected: 6,
+                found: numbers.len(),
+            })?;
 
-        let drawing = LotteryDrawing {
+        // 게임 정의에 맞는지는 add_drawing에서 검사
+        self.add_drawing(LotteryDrawing {
             round,
             numbers: numbers_array,
             bonus,
-        };
-
-        self.drawings.push(drawing);
-        self.drawings.sort_by_key(|d| d.round);
+        })?;
         Ok(())
     }
 
     /// 현재 저장된 회차 범위 조회
     #[wasm_bindgen(js_name = getRoundRange)]
     pub fn get_round_range(&self) -> JsValue {
-        if self.drawings.is_empty() {
-            return JsValue::NULL;
+        match self.round_range() {
+            Some(range) => serde_wasm_bindgen::to_value(&(range.min, range.max, range.count)).unwrap(),
+            None => JsValue::NULL,
         }
-
-        let min_round = self.drawings.iter().map(|d| d.round).min().unwrap();
-        let max_round = self.drawings.iter().map(|d| d.round).max().unwrap();
-
-        serde_wasm_bindgen::to_value(&(min_round, max_round, self.drawings.len())).unwrap()
     }
 
     /// 현재 데이터를 JSON으로 내보내기
@@ -107,121 +337,348 @@ impl LotteryEngine {
     }
 }
 
-// Internal methods
+// Rust API (CLI 및 rlib 사용자용)
 impl LotteryEngine {
-    fn get_used_combinations(&self) -> HashSet<[u8; 6]> {
-        let mut used_combinations = HashSet::new();
+    /// 파싱된 회차 데이터로 엔진 생성 (로또 6/45, 전체 데이터 검증 후 회차순 정렬)
+    pub fn from_drawings(drawings: Vec<LotteryDrawing>) -> Result<LotteryEngine, LotteryError> {
+        LotteryEngine::with_game(GameSpec::lotto_645(), drawings)
+    }
 
-        // 1등 번호 조합
-        for drawing in &self.drawings {
-            let mut sorted_numbers = drawing.numbers.clone();
-            sorted_numbers.sort();
-            used_combinations.insert(sorted_numbers);
+    /// 게임 정의를 지정해 엔진 생성 (6개 선택 + 같은 추첨기 보너스 1개 게임만 지원)
+    pub fn with_game(game: GameSpec, mut drawings: Vec<LotteryDrawing>) -> Result<LotteryEngine, LotteryError> {
+        if game.pick != 6 || game.bonus != (BonusRule::SameDrum { count: 1 }) {
+            return Err(LotteryError::UnsupportedGame(game.name));
         }
 
-        // 2등 번호 조합 (1등 5개 + 보너스)
+        validate_with(&game, &drawings).into_result()?;
+        drawings.sort_by_key(|d| d.round);
+
+        Ok(LotteryEngine { game, drawings })
+    }
+
+    /// 엔진의 게임 정의
+    pub fn game(&self) -> &GameSpec {
+        &self.game
+    }
+
+    /// 저장된 전체 회차 데이터
+    pub fn drawings(&self) -> &[LotteryDrawing] {
+        &self.drawings
+    }
+
+    /// 기존 1등, 2등 당첨번호 조합 (비트마스크)
+    pub fn used_masks(&self) -> HashSet<TicketMask> {
+        let mut used_masks = HashSet::new();
+
         for drawing in &self.drawings {
-            for i in 0..6 {
-                let mut second_place_combo = drawing.numbers.clone();
-                second_place_combo[i] = drawing.bonus;
-                second_place_combo.sort();
-                used_combinations.insert(second_place_combo);
+            // 1등 번호 조합
+            let first_place = drawing.mask();
+            used_masks.insert(first_place);
+
+            // 2등 번호 조합 (1등 5개 + 보너스)
+            for num in first_place.iter() {
+                used_masks.insert(first_place.without(num).with(drawing.bonus));
             }
         }
 
-        used_combinations
+        used_masks
     }
 
-    fn generate_sets(&self, count: usize) -> Vec<[u8; 6]> {
-        let used_combinations = self.get_used_combinations();
-        let mut rng = thread_rng();
-        let all_numbers: Vec<u8> = (1..=45).collect();
-        let mut results = Vec::new();
-        let mut attempts = 0;
-        let max_attempts = count * 1000;
+    /// 기존 1등, 2등 당첨번호 조합 (정렬된 배열)
+    pub fn used_combinations(&self) -> HashSet<[u8; 6]> {
+        self.used_masks()
+            .into_iter()
+            .filter_map(TicketMask::to_ticket)
+            .collect()
+    }
 
-        while results.len() < count && attempts < max_attempts {
-            attempts += 1;
-            let mut selected: Vec<u8> = all_numbers.choose_multiple(&mut rng, 6).cloned().collect();
-            selected.sort();
+    /// 기존 1등, 2등 조합의 조합 인덱스
+    pub fn used_ranks(&self) -> HashSet<u32> {
+        self.used_masks()
+            .into_iter()
+            .map(|mask| combinadic::rank_combination(&mask.iter().collect::<Vec<u8>>()) as u32)
+            .collect()
+    }
 
-            let selected_array: [u8; 6] = selected.try_into().unwrap();
+    /// 기존 1등, 2등 조합을 제외한 번호 세트를 정확히 count개 생성 (seed가 같으면 같은 결과)
+    pub fn generate_sets(&self, count: usize, seed: Option<u64>) -> Result<Vec<[u8; 6]>, LotteryError> {
+        self.generate_sets_excluding(&ExclusionPolicy::default(), &[], count, seed)
+    }
 
-            if !used_combinations.contains(&selected_array) && !results.contains(&selected_array) {
-                results.push(selected_array);
-            }
+    /// 필수 번호를 포함한 번호 세트 생성 (seed가 같으면 같은 결과)
+    pub fn generate_sets_with_required(&self, required_numbers: &[u8], count: usize, seed: Option<u64>) -> Result<Vec<[u8; 6]>, LotteryError> {
+        if required_numbers.is_empty() {
+            return Err(LotteryError::RequiredCountOutOfRange { max: self.game.pick });
         }
 
-        results
+        self.generate_sets_excluding(&ExclusionPolicy::default(), required_numbers, count, seed)
+    }
+
+    /// 제외 정책을 지정해 번호 세트 생성 (필수 번호는 비어 있어도 됨)
+    pub fn generate_sets_excluding(&self, policy: &ExclusionPolicy, required_numbers: &[u8], count: usize, seed: Option<u64>) -> Result<Vec<[u8; 6]>, LotteryError> {
+        self.generate_sets_constrained(policy, None, &NumberConstraints::with_required(required_numbers), count, seed)
     }
 
-    fn generate_sets_with_required(&self, required_numbers: &[u8], count: usize) -> Result<Vec<[u8; 6]>, String> {
-        let used_combinations = self.get_used_combinations();
-        let mut rng = thread_rng();
-        let remaining_numbers: Vec<u8> = (1..=45)
-            .filter(|&n| !required_numbers.contains(&n))
-            .collect();
+    /// 번호 제약(필수/제외 번호, 번호 풀)을 만족하는 번호 세트 생성
+    ///
+    /// 조건을 만족하는 조합이 count개보다 적으면 정확한 유효 조합 수와 함께 InsufficientCombinations를 반환합니다.
+    pub fn generate_sets_constrained(&self, policy: &ExclusionPolicy, filter: Option<&dyn Filter>, constraints: &NumberConstraints, count: usize, seed: Option<u64>) -> Result<Vec<[u8; 6]>, LotteryError> {
+        let exclusion = self.exclusion(policy)?;
+        let tickets = self.game
+            .generate_constrained(&exclusion, filter, constraints, count, &mut seeded_rng(seed))?;
 
-        let needed_count = 6 - required_numbers.len();
-        let mut results = Vec::new();
-        let mut attempts = 0;
-        let max_attempts = count * 1000;
+        Ok(to_sets(tickets))
+    }
 
-        while results.len() < count && attempts < max_attempts {
-            attempts += 1;
-            let mut selected = required_numbers.to_vec();
-            let additional: Vec<u8> = remaining_numbers
-                .choose_multiple(&mut rng, needed_count)
-                .cloned()
-                .collect();
-            selected.extend(additional);
-            selected.sort();
+    /// 제외 정책과 필터를 함께 적용해 번호 세트 생성
+    pub fn generate_sets_filtered(&self, policy: &ExclusionPolicy, filter: &dyn Filter, required_numbers: &[u8], count: usize, seed: Option<u64>) -> Result<Vec<[u8; 6]>, LotteryError> {
+        self.generate_sets_constrained(policy, Some(filter), &NumberConstraints::with_required(required_numbers), count, seed)
+    }
 
-            let selected_array: [u8; 6] = selected.try_into().unwrap();
+    /// 저장된 회차로 추천기 생성
+    pub fn recommender(&self, config: RecommenderConfig) -> Result<Recommender, LotteryError> {
+        Recommender::new(&self.game, &self.drawings, config)
+    }
 
-            if !used_combinations.contains(&selected_array) && !results.contains(&selected_array) {
-                results.push(selected_array);
-            }
-        }
+    /// 제외 조합과 필터를 통과한 후보 중 점수 상위 count개 (seed가 같으면 같은 결과)
+    pub fn recommend(&self, config: &RecommenderConfig, policy: &ExclusionPolicy, filter: &dyn Filter, count: usize, seed: Option<u64>) -> Result<Vec<ScoredTicket>, LotteryError> {
+        let recommender = self.recommender(*config)?;
+        let exclusion = self.exclusion(policy)?;
+        recommender.recommend(&exclusion, filter, count, &mut seeded_rng(seed))
+    }
 
-        if results.len() < count {
-            return Err(format!("조건에 맞는 번호 조합을 {}개 찾을 수 없습니다. ({}개만 생성됨)", count, results.len()));
-        }
+    /// 점수 상위 후보에서 번호가 고르게 섞이도록 count개 추천 (seed가 같으면 같은 결과)
+    pub fn recommend_diverse(&self, config: &RecommenderConfig, diversity: &DiversityConfig, policy: &ExclusionPolicy, filter: &dyn Filter, count: usize, seed: Option<u64>) -> Result<DiverseSelection, LotteryError> {
+        let recommender = self.recommender(*config)?;
+        let exclusion = self.exclusion(policy)?;
+        Ok(recommender.recommend_diverse(&exclusion, filter, count, diversity, &mut seeded_rng(seed)))
+    }
+
+    /// 풀의 모든 조합으로 만든 휠 (키 번호는 모든 티켓에 포함)
+    pub fn full_wheel(&self, pool: &[u8], keys: &[u8], policy: &ExclusionPolicy) -> Result<Wheel, LotteryError> {
+        full_wheel(&self.game, pool, keys, &self.exclusion(policy)?)
+    }
 
-        Ok(results)
+    /// 보장 조건을 만족하는 축약 휠
+    pub fn abbreviated_wheel(&self, pool: &[u8], keys: &[u8], guarantee: WheelGuarantee, policy: &ExclusionPolicy) -> Result<Wheel, LotteryError> {
+        abbreviated_wheel(&self.game, pool, keys, guarantee, &self.exclusion(policy)?)
     }
 
-    fn calculate_frequency(&self) -> Vec<(u8, usize)> {
-        let mut frequency = [0usize; 46];
+    /// 보장 조건을 만족하는 더 적은 티켓 탐색 (시간 예산 안에서 축약 휠을 개선)
+    pub fn search_covering(&self, pool: &[u8], keys: &[u8], guarantee: WheelGuarantee, policy: &ExclusionPolicy, config: &CoveringConfig) -> Result<CoveringSearch, LotteryError> {
+        search_covering(&self.game, pool, keys, guarantee, &self.exclusion(policy)?, config)
+    }
 
-        // 1등 번호 빈도
-        for drawing in &self.drawings {
-            for &num in &drawing.numbers {
-                if num >= 1 && num <= 45 {
-                    frequency[num as usize] += 1;
-                }
-            }
-        }
+    /// 필터 설정으로 필터 묶음 생성 (직전 회차 겹침은 마지막 회차 기준)
+    pub fn filters(&self, config: &FilterConfig) -> FilterSet {
+        let previous = self.drawings.last().map(|drawing| drawing.sorted_numbers());
+        config.build(previous.as_ref().map(|numbers| numbers.as_slice()))
+    }
 
-        // 보너스 번호 빈도 (2등 영향)
-        for drawing in &self.drawings {
-            if drawing.bonus >= 1 && drawing.bonus <= 45 {
-                frequency[drawing.bonus as usize] += 1;
-            }
+    /// 저장된 회차 기준 제외 조합
+    pub fn exclusion(&self, policy: &ExclusionPolicy) -> Result<Exclusion, LotteryError> {
+        self.game.exclusion(policy, &self.drawings)
+    }
+
+    /// 필수 번호를 포함하면서 기존 1등, 2등 조합이 아닌 조합의 개수
+    pub fn count_valid_combinations(&self, required_numbers: &[u8]) -> Result<u64, LotteryError> {
+        self.count_valid_combinations_excluding(&ExclusionPolicy::default(), required_numbers)
+    }
+
+    /// 필수 번호를 포함하면서 제외 정책에 걸리지 않는 조합의 개수
+    pub fn count_valid_combinations_excluding(&self, policy: &ExclusionPolicy, required_numbers: &[u8]) -> Result<u64, LotteryError> {
+        self.game.count_valid(&self.exclusion(policy)?, required_numbers)
+    }
+
+    /// 번호 제약을 만족하면서 제외 정책에 걸리지 않는 조합의 개수
+    pub fn count_valid_combinations_constrained(&self, policy: &ExclusionPolicy, constraints: &NumberConstraints) -> Result<u64, LotteryError> {
+        self.game.count_valid_constrained(&self.exclusion(policy)?, constraints)
+    }
+
+    /// 번호별 출현 빈도 (1등 + 보너스, 낮은 빈도순)
+    pub fn number_frequency(&self) -> Vec<(u8, usize)> {
+        self.game.frequency(&self.drawings)
+    }
+
+    /// 구간별 본번호/보너스/합계 빈도
+    pub fn frequency_report(&self, query: &FrequencyQuery) -> Result<FrequencyReport, LotteryError> {
+        self.game.frequency_report(&self.drawings, query)
+    }
+
+    /// 구간 안 번호 쌍(선택 시 삼중) 동시출현
+    pub fn cooccurrence_report(&self, query: &CooccurrenceQuery) -> Result<CooccurrenceReport, LotteryError> {
+        self.game.cooccurrence_report(&self.drawings, query)
+    }
+
+    /// 특징별 정확한 이론 분포와 구간 회차 분포 비교 (합계, 홀수, 저번호, 연속 쌍, 십의 자리 구간 수, 최대 간격)
+    pub fn distribution_report(&self, window: RoundWindow) -> Result<DistributionReport, LotteryError> {
+        self.game.distribution_report(&self.drawings, window)
+    }
+
+    /// 필터 조건별/전체 조합 통과 비율과 구간 당첨번호 통과 비율 (겹침 조건은 마지막 회차 기준)
+    pub fn filter_coverage(&self, config: &FilterConfig, window: RoundWindow) -> Result<FilterCoverage, LotteryError> {
+        let previous = self.drawings.last().map(|drawing| drawing.sorted_numbers());
+        self.game.filter_coverage(&self.drawings, window, config, previous.as_ref().map(|numbers| numbers.as_slice()))
+    }
+
+    /// 구간 안 회차들의 무작위성 검정 (빈도 카이제곱, 홀짝 런, 합계 계열 상관, 갭, 합계 KS)
+    pub fn randomness_report(&self, window: RoundWindow) -> Result<RandomnessReport, LotteryError> {
+        self.game.randomness_report(&self.drawings, window)
+    }
+
+    /// 번호별 미출현 갭 (percentile 백분위수를 넘으면 과출 표시)
+    pub fn gap_report(&self, percentile: f64) -> Result<GapReport, LotteryError> {
+        self.game.gap_report(&self.drawings, percentile)
+    }
+
+    /// 회차 조회
+    pub fn drawing(&self, round: u32) -> Option<&LotteryDrawing> {
+        self.drawings
+            .binary_search_by_key(&round, |d| d.round)
+            .ok()
+            .map(|index| &self.drawings[index])
+    }
+
+    /// 티켓 한 장의 당첨 등수
+    pub fn check_ticket(&self, ticket: &[u8; 6], round: u32) -> Result<PrizeTier, LotteryError> {
+        Ok(self.check_tickets(std::slice::from_ref(ticket), round)?[0].tier)
+    }
+
+    /// 여러 티켓을 한 회차 결과와 비교
+    pub fn check_tickets(&self, tickets: &[[u8; 6]], round: u32) -> Result<Vec<TicketCheck>, LotteryError> {
+        let drawing = self.drawing(round).ok_or(LotteryError::RoundNotFound(round))?;
+
+        tickets
+            .iter()
+            .map(|ticket| {
+                self.game.check_numbers(ticket)?;
+                check_ticket(ticket, drawing)
+            })
+            .collect()
+    }
+
+    /// 티켓 1장의 세전/세후 기대값
+    pub fn expected_value(&self, params: &EvParams) -> Result<EvReport, LotteryError> {
+        expected_value(&self.game, params)
+    }
+
+    /// 티켓 묶음을 한 회차에 함께 샀을 때의 당첨 확률
+    pub fn odds(&self, tickets: &[[u8; 6]]) -> Result<OddsReport, LotteryError> {
+        ticket_odds(&self.game, tickets)
+    }
+
+    /// 저장된 데이터로 전략 백테스트
+    pub fn backtest(&self, strategy: &dyn Strategy, config: &BacktestConfig) -> Result<BacktestReport, LotteryError> {
+        backtest(&self.game, &self.drawings, strategy, config)
+    }
+
+    /// 전략을 장기간 반복 구매하는 몬테카를로 시뮬레이션 (자동/반자동 번호는 제외 정책 적용, 멀티스레드)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn simulate(&self, config: &SimulationConfig, policy: &ExclusionPolicy) -> Result<SimulationReport, LotteryError> {
+        simulate(&self.game, &self.drawings, &self.exclusion(policy)?, config)
+    }
+
+    /// 회차 추가 후 회차순 정렬 (이미 있는 회차는 거부)
+    pub fn add_drawing(&mut self, drawing: LotteryDrawing) -> Result<(), LotteryError> {
+        self.game.check_draw(&drawing)?;
+        if self.drawings.iter().any(|d| d.round == drawing.round) {
+            return Err(LotteryError::DuplicateRound(drawing.round));
         }
 
-        let mut freq_pairs: Vec<(u8, usize)> = (1..=45)
-            .map(|num| (num, frequency[num as usize]))
-            .collect();
+        self.drawings.push(drawing);
+        self.drawings.sort_by_key(|d| d.round);
+        Ok(())
+    }
+
+    /// 저장된 회차 범위
+    pub fn round_range(&self) -> Option<RoundRange> {
+        let min = self.drawings.iter().map(|d| d.round).min()?;
+        let max = self.drawings.iter().map(|d| d.round).max()?;
 
-        freq_pairs.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
-        freq_pairs
+        Some(RoundRange {
+            min,
+            max,
+            count: self.drawings.len(),
+        })
     }
 }
 
+// JS number[][] → [u8; 6] 목록
+fn tickets_from_js(tickets: JsValue) -> Result<Vec<[u8; 6]>, LotteryError> {
+    let tickets: Vec<Vec<u8>> = serde_wasm_bindgen::from_value(tickets)
+        .map_err(|e| LotteryError::InvalidInput(e.to_string()))?;
+
+    tickets
+        .into_iter()
+        .map(|numbers| {
+            numbers.try_into().map_err(|numbers: Vec<u8>| LotteryError::InvalidNumberCount {
+                expected: 6,
+                found: numbers.len(),
+            })
+        })
+        .collect()
+}
+
+// JS 설정 객체 (undefined/null이면 기본값)
+fn from_js_or_default<T: Default + serde::de::DeserializeOwned>(value: JsValue) -> Result<T, LotteryError> {
+    if value.is_undefined() || value.is_null() {
+        return Ok(T::default());
+    }
+
+    serde_wasm_bindgen::from_value(value).map_err(|e| LotteryError::InvalidInput(e.to_string()))
+}
+
+// JS 제외 정책 문자열 (생략하면 기본값)
+fn parse_policy(exclusion: Option<String>) -> Result<ExclusionPolicy, LotteryError> {
+    exclusion.map_or(Ok(ExclusionPolicy::default()), |policy| policy.parse())
+}
+
+fn to_sets(tickets: Vec<Ticket>) -> Vec<[u8; 6]> {
+    tickets
+        .into_iter()
+        .filter_map(|ticket| ticket.numbers.try_into().ok())
+        .collect()
+}
+
 // WASM 의존성 추가
 #[wasm_bindgen(start)]
 pub fn main() {
     #[cfg(target_arch = "wasm32")]
     console_error_panic_hook::set_once();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 테스트 벡터 기준 회차 (lottery_data.json은 매주 갱신되므로 이후 회차는 뺌)
+    const VECTOR_LAST_ROUND: u32 = 1230;
+
+    fn engine() -> LotteryEngine {
+        let drawings: Vec<LotteryDrawing> = serde_json::from_str(include_str!("../lottery_data.json")).unwrap();
+        let drawings = drawings.into_iter().filter(|drawing| drawing.round <= VECTOR_LAST_ROUND).collect();
+        LotteryEngine::from_drawings(drawings).unwrap()
+    }
+
+    // README "테스트 벡터" 표와 같은 값
+    #[test]
+    fn seeded_sets_match_readme_vectors() {
+        let engine = engine();
+        assert_eq!(engine.drawings.len(), VECTOR_LAST_ROUND as usize);
+
+        let vectors: [(u64, [[u8; 6]; 5]); 3] = [
+            (0, [[1, 9, 21, 37, 38, 41], [3, 5, 10, 23, 24, 30], [4, 7, 14, 25, 34, 37], [3, 5, 11, 12, 23, 24], [6, 9, 33, 35, 37, 39]]),
+            (42, [[1, 5, 18, 21, 27, 36], [8, 15, 27, 37, 41, 42], [3, 5, 20, 33, 43, 45], [4, 7, 13, 17, 26, 33], [1, 5, 17, 22, 27, 40]]),
+            (20261018, [[17, 25, 27, 37, 38, 41], [3, 5, 9, 10, 18, 24], [8, 10, 12, 22, 39, 43], [18, 19, 21, 29, 39, 40], [8, 9, 15, 29, 33, 41]]),
+        ];
+        for (seed, expected) in vectors {
+            assert_eq!(engine.generate_sets(5, Some(seed)).unwrap(), expected, "seed {}", seed);
+        }
+
+        let required = engine.generate_sets_with_required(&[7, 23], 5, Some(42)).unwrap();
+        assert_eq!(
+            required,
+            [[1, 7, 19, 22, 23, 29], [7, 9, 23, 30, 34, 44], [3, 7, 20, 21, 23, 36], [5, 7, 23, 32, 43, 45], [4, 7, 18, 23, 28, 35]]
+        );
+    }
+}
@@ -1,19 +1,14 @@
 use scraper::{Html, Selector};
-use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use std::fs::{File, read_dir};
 use std::io::{Read, Write};
 use std::path::Path;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
 use encoding_rs::EUC_KR;
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct LotteryDrawing {
-    round: u32,
-    numbers: [u8; 6],  // 당첨번호 6개
-    bonus: u8,         // 보너스번호
-}
+use lottery::{
+    consecutive_pairs, random_seed, validate, BacktestConfig, CooccurrenceQuery, CoveringConfig, DiversityConfig, DrawSource,
+    EvParams, ExclusionPolicy, Feature, Filter, FilterConfig, FilterSet, FrequencyKind, FrequencyQuery, LotteryDrawing, LotteryEngine,
+    LotteryError, NumberConstraints, PlayStrategy, PrizeTier, RecommenderConfig, RequiredStrategy, RoundPrizes, RoundWindow, SimulationConfig,
+    SortOrder, Strategy, UniformStrategy, WheelGuarantee, DRAWS_PER_YEAR, MAX_WHEEL_POOL,
+};
 
 struct LotteryParser {
     drawings: Vec<LotteryDrawing>,
@@ -29,39 +24,10 @@ impl LotteryParser {
     fn parse_excel_file(&mut self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
         println!("엑셀 파일 파싱 중: {}", file_path);
         
-        let mut file = File::open(file_path)?;
-        let mut buffer = Vec::new();
-        file.read_to_end(&mut buffer)?;
-        
-        // EUC-KR 인코딩을 UTF-8로 변환
-        let (contents, _, _) = EUC_KR.decode(&buffer);
-        
-        let document = Html::parse_document(&contents);
-        let row_selector = Selector::parse("tr").unwrap();
-        
-        for row in document.select(&row_selector) {
-            let cells: Vec<String> = row
-                .select(&Selector::parse("td").unwrap())
-                .map(|cell| cell.inner_html().trim().to_string())
-                .collect();
-            
+        for cells in read_excel_rows(file_path)? {
             // 데이터가 있는 행인지 확인 - 더 유연한 조건
             if cells.len() >= 17 {
-                // 첫 번째 또는 두 번째 셀에서 회차 찾기
-                let mut round_opt: Option<u32> = None;
-
-                for i in 0..=1 {
-                    if i < cells.len() {
-                        if let Ok(round) = cells[i].parse::<u32>() {
-                            if round > 0 && round <= 2000 {  // 합리적인 회차 범위
-                                round_opt = Some(round);
-                                break;
-                            }
-                        }
-                    }
-                }
-                
-                if let Some(round) = round_opt {
+                if let Some((_, round)) = find_round_cell(&cells) {
                     // 당첨번호를 찾기 - 맨 뒤에서부터 7개 셀에서 찾기
                     let start_index = if cells.len() >= 7 { cells.len() - 7 } else { 0 };
                     
@@ -70,9 +36,9 @@ impl LotteryParser {
                     let mut valid_count = 0;
                     
                     // 뒤에서부터 7개 셀에서 숫자 찾기
-                    for i in start_index..cells.len() {
-                        if let Ok(num) = cells[i].parse::<u8>() {
-                            if num >= 1 && num <= 45 {
+                    for cell in &cells[start_index..] {
+                        if let Ok(num) = cell.parse::<u8>() {
+                            if (1..=45).contains(&num) {
                                 if valid_count < 6 {
                                     numbers[valid_count] = num;
                                 } else if valid_count == 6 {
@@ -85,11 +51,7 @@ impl LotteryParser {
                     
                     // 정확히 7개(당첨번호 6개 + 보너스 1개)가 파싱되었는지 확인
                     if valid_count == 7 && bonus > 0 {
-                        let drawing = LotteryDrawing {
-                            round,
-                            numbers,
-                            bonus,
-                        };
+                        let drawing = LotteryDrawing::new(round, numbers, bonus)?;
                         self.drawings.push(drawing);
                         println!("파싱 완료: {}회차 - 번호: {:?}, 보너스: {}", round, numbers, bonus);
                     }
@@ -100,36 +62,6 @@ impl LotteryParser {
         Ok(())
     }
 
-    fn save_to_text_file(&self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let mut file = File::create(file_path)?;
-        
-        // 헤더 작성
-        writeln!(file, "회차,당첨번호1,당첨번호2,당첨번호3,당첨번호4,당첨번호5,당첨번호6,보너스번호")?;
-        
-        // 데이터 작성 (회차 순으로 정렬)
-        let mut sorted_drawings = self.drawings.clone();
-        sorted_drawings.sort_by_key(|d| d.round);
-        
-        for drawing in sorted_drawings {
-            writeln!(
-                file,
-                "{},{},{},{},{},{},{},{}",
-                drawing.round,
-                drawing.numbers[0],
-                drawing.numbers[1],
-                drawing.numbers[2],
-                drawing.numbers[3],
-                drawing.numbers[4],
-                drawing.numbers[5],
-                drawing.bonus
-            )?;
-        }
-        
-        println!("텍스트 파일 저장 완료: {}", file_path);
-        println!("총 {}개의 회차 데이터 저장됨", self.drawings.len());
-        Ok(())
-    }
-
     fn load_from_text_file(&mut self, file_path: &str) -> Result<bool, Box<dyn std::error::Error>> {
         if !Path::new(file_path).exists() {
             println!("텍스트 파일이 존재하지 않습니다: {}", file_path);
@@ -144,201 +76,173 @@ impl LotteryParser {
         
         for (line_num, line) in contents.lines().enumerate() {
             if line_num == 0 { continue; } // 헤더 스킵
-            
-            let parts: Vec<&str> = line.split(',').collect();
-            if parts.len() >= 8 {
-                if let (Ok(round), Ok(n1), Ok(n2), Ok(n3), Ok(n4), Ok(n5), Ok(n6), Ok(bonus)) = (
-                    parts[0].parse::<u32>(),
-                    parts[1].parse::<u8>(),
-                    parts[2].parse::<u8>(),
-                    parts[3].parse::<u8>(),
-                    parts[4].parse::<u8>(),
-                    parts[5].parse::<u8>(),
-                    parts[6].parse::<u8>(),
-                    parts[7].parse::<u8>(),
-                ) {
-                    let drawing = LotteryDrawing {
-                        round,
-                        numbers: [n1, n2, n3, n4, n5, n6],
-                        bonus,
-                    };
-                    self.drawings.push(drawing);
-                }
-            }
+            if line.trim().is_empty() { continue; }
+
+            let drawing = parse_text_line(line_num + 1, line)?;
+            self.drawings.push(drawing);
         }
         
         println!("텍스트 파일에서 {}개 회차 로드됨", self.drawings.len());
         Ok(true)
     }
 
-    fn get_used_combinations(&self) -> HashSet<[u8; 6]> {
-        let mut used_combinations = HashSet::new();
-        
-        // 기존 1등 번호 조합들을 HashSet에 저장
-        for drawing in &self.drawings {
-            let mut sorted_numbers = drawing.numbers.clone();
-            sorted_numbers.sort();
-            used_combinations.insert(sorted_numbers);
-        }
+    fn parse_all_excel_files(&mut self, static_dir: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let entries = read_dir(static_dir)?;
         
-        // 2등 번호 조합도 제외 (1등 5개 + 보너스 1개)
-        for drawing in &self.drawings {
-            for i in 0..6 {
-                let mut second_place_combo = drawing.numbers.clone();
-                second_place_combo[i] = drawing.bonus;
-                second_place_combo.sort();
-                used_combinations.insert(second_place_combo);
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            
+            if let Some(extension) = path.extension() {
+                if extension == "xls" || extension == "xlsx" {
+                    if let Some(path_str) = path.to_str() {
+                        self.parse_excel_file(path_str)?;
+                    }
+                }
             }
         }
         
-        used_combinations
+        Ok(())
     }
 
-
-
-    fn generate_numbers_sets_with_required(&self, required_numbers: &[u8], count: usize) -> Result<Vec<[u8; 6]>, String> {
-        if required_numbers.is_empty() || required_numbers.len() > 6 {
-            return Err("필수 번호는 1-6개 사이여야 합니다.".to_string());
-        }
-
-        for &num in required_numbers {
-            if num < 1 || num > 45 {
-                return Err("번호는 1-45 사이여야 합니다.".to_string());
-            }
+    fn into_engine(self) -> Result<LotteryEngine, LotteryError> {
+        // 빠진 회차는 경고로만 표시
+        for warning in validate(&self.drawings).warnings() {
+            println!("경고: {}", warning);
         }
 
-        // 중복 체크
-        let mut unique_check = HashSet::new();
-        for &num in required_numbers {
-            if !unique_check.insert(num) {
-                return Err("중복된 번호가 있습니다.".to_string());
-            }
-        }
+        LotteryEngine::from_drawings(self.drawings)
+    }
+}
 
-        let used_combinations = self.get_used_combinations();
-        let mut rng = thread_rng();
-        let remaining_numbers: Vec<u8> = (1..=45)
-            .filter(|&n| !required_numbers.contains(&n))
-            .collect();
+// 엑셀(HTML 표) 파일의 행별 셀 텍스트
+fn read_excel_rows(file_path: &str) -> Result<Vec<Vec<String>>, Box<dyn std::error::Error>> {
+    let mut file = File::open(file_path)?;
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer)?;
 
-        let needed_count = 6 - required_numbers.len();
-        let mut results = Vec::new();
-        let mut attempts = 0;
-        let max_attempts = count * 1000;
+    // EUC-KR 인코딩을 UTF-8로 변환
+    let (contents, _, _) = EUC_KR.decode(&buffer);
 
-        while results.len() < count && attempts < max_attempts {
-            attempts += 1;
-            let mut selected = required_numbers.to_vec();
-            let additional: Vec<u8> = remaining_numbers
-                .choose_multiple(&mut rng, needed_count)
-                .cloned()
-                .collect();
-            selected.extend(additional);
-            selected.sort();
+    let document = Html::parse_document(&contents);
+    let row_selector = Selector::parse("tr").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
 
-            let selected_array: [u8; 6] = selected.try_into().unwrap();
+    Ok(document
+        .select(&row_selector)
+        .map(|row| {
+            row.select(&cell_selector)
+                .map(|cell| cell.inner_html().trim().to_string())
+                .collect()
+        })
+        .collect())
+}
 
-            if !used_combinations.contains(&selected_array) && !results.contains(&selected_array) {
-                results.push(selected_array);
-            }
-        }
+// 첫 번째 또는 두 번째 셀에서 회차 찾기 (연도 셀은 해당 연도 첫 행에만 있음)
+fn find_round_cell(cells: &[String]) -> Option<(usize, u32)> {
+    cells.iter().take(2).enumerate().find_map(|(i, cell)| {
+        cell.parse::<u32>()
+            .ok()
+            .filter(|&round| round > 0 && round <= 2000)  // 합리적인 회차 범위
+            .map(|round| (i, round))
+    })
+}
 
-        if results.len() < count {
-            return Err(format!("조건에 맞는 번호 조합을 {}개 찾을 수 없습니다. ({}개만 생성됨)", count, results.len()));
-        }
+// 엑셀 파일들에서 회차별 당첨자 수/당첨금 읽기 (회차순)
+fn load_round_prizes(static_dir: &str) -> Result<Vec<RoundPrizes>, Box<dyn std::error::Error>> {
+    let mut prizes = Vec::new();
 
-        Ok(results)
-    }
+    for entry in read_dir(static_dir)? {
+        let path = entry?.path();
+        let is_excel = path.extension().is_some_and(|ext| ext == "xls" || ext == "xlsx");
+        let Some(path_str) = path.to_str().filter(|_| is_excel) else {
+            continue;
+        };
 
-    fn generate_numbers_sets(&self, count: usize) -> Vec<[u8; 6]> {
-        let used_combinations = self.get_used_combinations();
-        let mut rng = thread_rng();
-        let all_numbers: Vec<u8> = (1..=45).collect();
-        let mut results = Vec::new();
-        let mut attempts = 0;
-        let max_attempts = count * 1000;
-        
-        while results.len() < count && attempts < max_attempts {
-            attempts += 1;
-            let mut selected: Vec<u8> = all_numbers.choose_multiple(&mut rng, 6).cloned().collect();
-            selected.sort();
-            
-            let selected_array: [u8; 6] = selected.try_into().unwrap();
-            
-            if !used_combinations.contains(&selected_array) && !results.contains(&selected_array) {
-                results.push(selected_array);
+        for cells in read_excel_rows(path_str)? {
+            if cells.len() < 17 {
+                continue;
             }
-        }
-        
-        results
-    }
+            let Some((index, round)) = find_round_cell(&cells) else {
+                continue;
+            };
 
-    fn get_number_frequency(&self) -> Vec<(u8, usize)> {
-        let mut frequency = [0usize; 46]; // 인덱스 0은 사용하지 않고 1-45만 사용
-        
-        // 1등 번호 빈도 계산
-        for drawing in &self.drawings {
-            for &num in &drawing.numbers {
-                if num >= 1 && num <= 45 {
-                    frequency[num as usize] += 1;
+            // 회차, 추첨일 다음에 (당첨자수, 당첨금액) x 5
+            let amount = |cell: &String| cell.replace([',', '원'], "").parse::<u64>().ok();
+            let values: Option<Vec<u64>> = cells[index + 2..index + 12].iter().map(amount).collect();
+            if let Some(values) = values {
+                let mut round_prizes = RoundPrizes { round, winners: [0; 5], amounts: [0; 5] };
+                for tier in 0..5 {
+                    round_prizes.winners[tier] = values[tier * 2];
+                    round_prizes.amounts[tier] = values[tier * 2 + 1];
                 }
+                prizes.push(round_prizes);
             }
         }
-        
-        // 보너스 번호도 2등에 영향을 주므로 포함
-        for drawing in &self.drawings {
-            if drawing.bonus >= 1 && drawing.bonus <= 45 {
-                frequency[drawing.bonus as usize] += 1;
-            }
-        }
-        
-        // (번호, 빈도) 쌍으로 변환하고 빈도 기준 오름차순 정렬
-        let mut freq_pairs: Vec<(u8, usize)> = (1..=45)
-            .map(|num| (num, frequency[num as usize]))
-            .collect();
-        
-        freq_pairs.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
-        freq_pairs
     }
 
-    fn add_new_drawing(&mut self, round: u32, numbers: [u8; 6], bonus: u8) {
-        let drawing = LotteryDrawing {
-            round,
-            numbers,
-            bonus,
-        };
-        self.drawings.push(drawing);
-        self.drawings.sort_by_key(|d| d.round);
+    prizes.sort_by_key(|p| p.round);
+    Ok(prizes)
+}
+
+// 텍스트 파일 한 줄 파싱 (회차,번호1..번호6,보너스)
+fn parse_text_line(line_num: usize, line: &str) -> Result<LotteryDrawing, LotteryError> {
+    let parts: Vec<&str> = line.split(',').map(|part| part.trim()).collect();
+    if parts.len() < 8 {
+        return Err(LotteryError::Parse {
+            line: line_num,
+            reason: format!("8개 항목이 필요합니다. ({}개)", parts.len()),
+        });
     }
 
-    fn get_round_range(&self) -> Option<(u32, u32)> {
-        if self.drawings.is_empty() {
-            return None;
-        }
+    let round = parts[0].parse::<u32>().map_err(|e| LotteryError::Parse {
+        line: line_num,
+        reason: format!("회차 '{}': {}", parts[0], e),
+    })?;
 
-        let min_round = self.drawings.iter().map(|d| d.round).min().unwrap();
-        let max_round = self.drawings.iter().map(|d| d.round).max().unwrap();
-        Some((min_round, max_round))
+    let mut values = [0u8; 7];
+    for (value, part) in values.iter_mut().zip(&parts[1..8]) {
+        *value = part.parse::<u8>().map_err(|e| LotteryError::Parse {
+            line: line_num,
+            reason: format!("번호 '{}': {}", part, e),
+        })?;
     }
 
-    fn parse_all_excel_files(&mut self, static_dir: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let entries = read_dir(static_dir)?;
-        
-        for entry in entries {
-            let entry = entry?;
-            let path = entry.path();
-            
-            if let Some(extension) = path.extension() {
-                if extension == "xls" || extension == "xlsx" {
-                    if let Some(path_str) = path.to_str() {
-                        self.parse_excel_file(path_str)?;
-                    }
-                }
-            }
-        }
-        
-        Ok(())
+    let numbers = [values[0], values[1], values[2], values[3], values[4], values[5]];
+    LotteryDrawing::new(round, numbers, values[6]).map_err(|e| LotteryError::Parse {
+        line: line_num,
+        reason: e.to_string(),
+    })
+}
+
+fn save_to_text_file(drawings: &[LotteryDrawing], file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut file = File::create(file_path)?;
+    
+    // 헤더 작성
+    writeln!(file, "회차,당첨번호1,당첨번호2,당첨번호3,당첨번호4,당첨번호5,당첨번호6,보너스번호")?;
+    
+    // 데이터 작성 (회차 순으로 정렬)
+    let mut sorted_drawings = drawings.to_vec();
+    sorted_drawings.sort_by_key(|d| d.round);
+    
+    for drawing in sorted_drawings {
+        writeln!(
+            file,
+            "{},{},{},{},{},{},{},{}",
+            drawing.round,
+            drawing.numbers[0],
+            drawing.numbers[1],
+            drawing.numbers[2],
+            drawing.numbers[3],
+            drawing.numbers[4],
+            drawing.numbers[5],
+            drawing.bonus
+        )?;
     }
+    
+    println!("텍스트 파일 저장 완료: {}", file_path);
+    println!("총 {}개의 회차 데이터 저장됨", drawings.len());
+    Ok(())
 }
 
 fn get_number_input(prompt: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
@@ -348,7 +252,6 @@ fn get_number_input(prompt: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>>
     std::io::stdin().read_line(&mut input)?;
 
     let numbers: Result<Vec<u8>, _> = input
-        .trim()
         .split_whitespace()
         .map(|s| s.parse::<u8>())
         .collect();
@@ -359,13 +262,228 @@ fn get_number_input(prompt: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>>
 fn show_menu() {
     println!("\n=== 로또 번호 추첨기 ===");
     println!("1. 새로운 로또 번호 추첨 (5개 세트)");
-    println!("2. 특정 수 포함 번호 추첨 (반자동, 5개 세트)");
-    println!("3. 수 추천 (빈도 기반)");
+    println!("2. 특정 수 포함/제외 번호 추첨 (반자동, 5개 세트)");
+    println!("3. 빈도 분석 (구간별 본번호/보너스)");
     println!("4. 신규 회차 추가");
-    println!("5. 종료");
+    println!("5. 당첨 확인");
+    println!("6. 백테스트 (전략 비교)");
+    println!("7. 기대값 계산");
+    println!("8. 점수 기반 추천");
+    println!("9. 휠링 (조합 보장)");
+    println!("10. 미출현 갭 분석");
+    println!("11. 동시출현 분석 (쌍/삼중)");
+    println!("12. 무작위성 검정");
+    println!("13. 이론 분포 비교 (합계/홀짝/저고/연속/구간/간격)");
+    println!("14. 장기 구매 시뮬레이션 (몬테카를로)");
+    println!("15. 종료");
+}
+
+// 메뉴 2의 포함/제외 번호와 번호 풀 입력 (Enter: 해당 조건 없음)
+fn read_constraints() -> Result<NumberConstraints, Box<dyn std::error::Error>> {
+    let required = get_number_input("포함할 번호들 (공백으로 구분, Enter: 없음): ")?;
+    let excluded = get_number_input("제외할 번호들 (공백으로 구분, Enter: 없음): ")?;
+    let pool = get_number_input("이 번호들 중에서만 선택 (공백으로 구분, Enter: 전체 번호): ")?;
+
+    Ok(NumberConstraints {
+        required,
+        excluded,
+        pool: if pool.is_empty() { None } else { Some(pool) },
+    })
+}
+
+fn read_input(prompt: &str) -> Result<String, Box<dyn std::error::Error>> {
+    print!("{}", prompt);
+    std::io::stdout().flush()?;
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+// 빈 입력이면 기본값, 숫자가 아니면 None
+fn parse_or<T: std::str::FromStr>(input: &str, default: T) -> Option<T> {
+    if input.is_empty() {
+        Some(default)
+    } else {
+        input.parse().ok()
+    }
+}
+
+// 회차 입력 (Enter: 최신 회차)
+fn read_round(engine: &LotteryEngine) -> Result<Option<u32>, Box<dyn std::error::Error>> {
+    let latest = match engine.round_range() {
+        Some(range) => range.max,
+        None => {
+            println!("현재 저장된 데이터가 없습니다.");
+            return Ok(None);
+        }
+    };
+
+    let input = read_input(&format!("회차 (Enter: 최신 {}회): ", latest))?;
+    if input.is_empty() {
+        return Ok(Some(latest));
+    }
+
+    match input.parse::<u32>() {
+        Ok(round) => Ok(Some(round)),
+        Err(_) => {
+            println!("올바른 회차를 입력해주세요.");
+            Ok(None)
+        }
+    }
+}
+
+fn check_tickets_menu(engine: &LotteryEngine) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 당첨 확인 ===");
+
+    let round = match read_round(engine)? {
+        Some(round) => round,
+        None => return Ok(()),
+    };
+
+    println!("번호 6개씩 한 줄에 입력하세요 (빈 줄: 입력 완료)");
+    let mut tickets = Vec::new();
+    loop {
+        match get_number_input(&format!("{}: ", tickets.len() + 1)) {
+            Ok(numbers) if numbers.is_empty() => break,
+            Ok(numbers) => match <[u8; 6]>::try_from(numbers) {
+                Ok(ticket) => tickets.push(ticket),
+                Err(_) => println!("6개의 번호를 입력해주세요."),
+            },
+            Err(_) => println!("올바른 번호를 입력해주세요."),
+        }
+    }
+
+    if tickets.is_empty() {
+        return Ok(());
+    }
+
+    match engine.check_tickets(&tickets, round) {
+        Ok(results) => {
+            if let Some(drawing) = engine.drawing(round) {
+                println!("\n{}회 당첨번호: {:?} + 보너스 {}", round, drawing.numbers, drawing.bonus);
+            }
+            for (i, result) in results.iter().enumerate() {
+                let bonus = if result.matched == 5 && result.bonus_matched { " + 보너스" } else { "" };
+                println!("{}: {:?} → {}개 일치{} ({})", i + 1, result.numbers, result.matched, bonus, result.tier);
+            }
+        }
+        Err(error) => {
+            println!("오류: {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+// --seed <n>: 모든 생성에 같은 시드 사용 (결과 재현용)
+fn parse_seed_arg() -> Result<Option<u64>, Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    match args.iter().position(|arg| arg == "--seed") {
+        Some(index) => {
+            let value = args.get(index + 1).ok_or("--seed 뒤에 시드 값을 입력해주세요.")?;
+            Ok(Some(value.parse::<u64>()?))
+        }
+        None => Ok(None),
+    }
+}
+
+// --exclude none|first|second|third|recent:N:K (기본: second)
+fn parse_exclusion_arg() -> Result<ExclusionPolicy, Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    match args.iter().position(|arg| arg == "--exclude") {
+        Some(index) => {
+            let value = args.get(index + 1).ok_or("--exclude 뒤에 제외 정책을 입력해주세요.")?;
+            Ok(value.parse::<ExclusionPolicy>()?)
+        }
+        None => Ok(ExclusionPolicy::default()),
+    }
+}
+
+// --filter default 또는 --filter sum=100-175,odd=2-4,... (없으면 필터 미사용)
+fn parse_filter_arg() -> Result<Option<FilterConfig>, Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    match args.iter().position(|arg| arg == "--filter") {
+        Some(index) => {
+            let value = args.get(index + 1).ok_or("--filter 뒤에 필터 설정을 입력해주세요.")?;
+            Ok(Some(value.parse::<FilterConfig>()?))
+        }
+        None => Ok(None),
+    }
+}
+
+// 제외 정책 + (있으면) 필터를 적용해 5개 세트 생성
+fn generate_sets(
+    engine: &LotteryEngine,
+    exclusion: &ExclusionPolicy,
+    filter: Option<&FilterSet>,
+    constraints: &NumberConstraints,
+    seed: u64,
+) -> Result<Vec<[u8; 6]>, LotteryError> {
+    let filter = filter.map(|filter| filter as &dyn Filter);
+    engine.generate_sets_constrained(exclusion, filter, constraints, 5, Some(seed))
+}
+
+// 세트 출력 (필터 사용 시 합계/홀짝/저고/연속 표시)
+fn print_number_sets(number_sets: &[[u8; 6]], filter: Option<&FilterSet>) {
+    for (i, numbers) in number_sets.iter().enumerate() {
+        if filter.is_none() {
+            println!("{}: {:?}", i + 1, numbers);
+            continue;
+        }
+
+        let sum: u32 = numbers.iter().map(|&n| n as u32).sum();
+        let odd = numbers.iter().filter(|&&n| n % 2 == 1).count();
+        let low = numbers.iter().filter(|&&n| n <= 22).count();
+        println!("{}: {:?}  합계 {}, 홀짝 {}:{}, 저고 {}:{}, 연속 {}",
+                 i + 1, numbers, sum, odd, 6 - odd, low, 6 - low, consecutive_pairs(numbers));
+    }
+
+    if let Some(filter) = filter {
+        println!("필터: {}", filter.name());
+    }
+}
+
+fn print_odds(engine: &LotteryEngine, number_sets: &[[u8; 6]]) {
+    let report = match engine.odds(number_sets) {
+        Ok(report) => report,
+        Err(error) => {
+            println!("확률 계산 오류: {}", error);
+            return;
+        }
+    };
+
+    println!("\n--- 당첨 확률 ---");
+    println!("티켓 1장 일치 개수별 확률:");
+    for matched in (0..=engine.game().pick).rev() {
+        let entries: Vec<_> = report.matches.iter().filter(|m| m.matched == matched).collect();
+        let probability: f64 = entries.iter().map(|m| m.probability).sum();
+        let with_bonus: f64 = entries.iter().filter(|m| m.bonus_matched > 0).map(|m| m.probability).sum();
+        if with_bonus > 0.0 {
+            println!("  {}개: 1/{:.1} (보너스 일치 1/{:.1})", matched, 1.0 / probability, 1.0 / with_bonus);
+        } else {
+            println!("  {}개: 1/{:.1}", matched, 1.0 / probability);
+        }
+    }
+
+    println!("\n{}장 중 한 장 이상 당첨 (서로 다른 번호 {}개, 등수 이상 기준):", report.tickets, report.distinct_numbers);
+    println!("{:<6}{:>14}{:>14}{:>14}{:>14}{:>14}{:>12}",
+             "등수", "1장", "묶음", "독립 가정", "평균 회차", "중앙값 회차", "평균 연수");
+    for tier in &report.tiers {
+        println!("{:<6}{:>14}{:>14}{:>14}{:>14.0}{:>14.0}{:>12.1}",
+                 tier.tier.to_string(),
+                 format!("1/{:.0}", 1.0 / tier.single),
+                 format!("1/{:.0}", tier.one_in),
+                 format!("1/{:.0}", 1.0 / tier.independent),
+                 tier.expected_draws,
+                 tier.median_draws,
+                 tier.expected_years);
+    }
+    println!("(매 회차 같은 티켓을 산다고 볼 때, 연 {}회 추첨)", DRAWS_PER_YEAR);
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let fixed_seed = parse_seed_arg()?;
+    let exclusion = parse_exclusion_arg()?;
     let mut parser = LotteryParser::new();
     
     // 기존 데이터가 있는지 확인하고 로드
@@ -378,16 +496,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         
         // 파싱한 데이터가 있으면 텍스트 파일로 저장
         if !parser.drawings.is_empty() {
-            parser.save_to_text_file("lottery_data.txt")?;
+            save_to_text_file(&parser.drawings, "lottery_data.txt")?;
         }
     } else {
         println!("기존 데이터를 사용합니다.");
     }
+
+    let mut engine = parser.into_engine()?;
+    let filter_config = parse_filter_arg()?;
     
     show_menu();
     
     loop {
-        print!("\n선택하세요 (1-5): ");
+        print!("\n선택하세요 (1-15): ");
         std::io::stdout().flush()?;
 
         let mut input = String::new();
@@ -405,25 +526,48 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         match input.trim() {
             "1" => {
-                let number_sets = parser.generate_numbers_sets(5);
-                println!("\n=== 추천 로또 번호 5개 세트 ===");
-                for (i, numbers) in number_sets.iter().enumerate() {
-                    println!("{}: {:?}", i + 1, numbers);
+                let seed = fixed_seed.unwrap_or_else(random_seed);
+                let filter = filter_config.map(|config| engine.filters(&config));
+                match generate_sets(&engine, &exclusion, filter.as_ref(), &NumberConstraints::default(), seed) {
+                    Ok(number_sets) => {
+                        println!("\n=== 추천 로또 번호 5개 세트 ===");
+                        print_number_sets(&number_sets, filter.as_ref());
+                        println!("({})", exclusion);
+                        println!("시드: {} (--seed {}로 재현 가능)", seed, seed);
+                        print_odds(&engine, &number_sets);
+                    }
+                    Err(error) => {
+                        println!("오류: {}", error);
+                    }
                 }
-                println!("(기존 1등, 2등 당첨번호 제외)");
                 show_menu();
             }
             "2" => {
-                match get_number_input("포함할 번호들 (공백으로 구분): ") {
-                    Ok(required_numbers) => {
-                        match parser.generate_numbers_sets_with_required(&required_numbers, 5) {
+                match read_constraints() {
+                    Ok(constraints) => {
+                        let seed = fixed_seed.unwrap_or_else(random_seed);
+                        let filter = filter_config.map(|config| engine.filters(&config));
+                        let number_sets = if constraints.is_empty() {
+                            Err(LotteryError::InvalidInput("포함, 제외, 번호 풀 중 하나 이상 입력해주세요.".to_string()))
+                        } else {
+                            generate_sets(&engine, &exclusion, filter.as_ref(), &constraints, seed)
+                        };
+                        match number_sets {
                             Ok(number_sets) => {
-                                println!("\n=== 특정 수 포함 추천 로또 번호 5개 세트 ===");
-                                for (i, numbers) in number_sets.iter().enumerate() {
-                                    println!("{}: {:?}", i + 1, numbers);
+                                println!("\n=== 특정 수 포함/제외 추천 로또 번호 5개 세트 ===");
+                                print_number_sets(&number_sets, filter.as_ref());
+                                if !constraints.required.is_empty() {
+                                    println!("포함된 수: {:?}", constraints.required);
+                                }
+                                if !constraints.excluded.is_empty() {
+                                    println!("제외된 수: {:?}", constraints.excluded);
+                                }
+                                if let Some(pool) = &constraints.pool {
+                                    println!("번호 풀: {:?} ({}개)", pool, pool.len());
                                 }
-                                println!("포함된 수: {:?}", required_numbers);
-                                println!("(기존 1등, 2등 당첨번호 제외)");
+                                println!("({})", exclusion);
+                                println!("시드: {} (--seed {}로 재현 가능)", seed, seed);
+                                print_odds(&engine, &number_sets);
                             }
                             Err(error) => {
                                 println!("오류: {}", error);
@@ -437,40 +581,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 show_menu();
             }
             "3" => {
-                let frequency = parser.get_number_frequency();
-                println!("\n=== 빈도 기반 수 추천 ===");
-                println!("가장 낮은 빈도순으로 정렬:");
-
-                let mut current_index = 0;
-                loop {
-                    if current_index >= frequency.len() {
-                        println!("모든 수를 추천했습니다.");
-                        break;
-                    }
-
-                    let (number, freq) = frequency[current_index];
-                    println!("추천 수: {} (빈도: {}회)", number, freq);
-                    current_index += 1;
-
-                    print!("다음 수를 보시겠습니까? (Enter: 다음, q: 종료): ");
-                    std::io::stdout().flush()?;
-                    let mut input = String::new();
-                    std::io::stdin().read_line(&mut input)?;
-
-                    if input.trim().to_lowercase() == "q" {
-                        break;
-                    }
-                }
+                frequency_menu(&engine)?;
                 show_menu();
             }
             "4" => {
                 println!("\n=== 신규 회차 추가 ===");
 
                 // 현재 저장된 회차 범위 표시
-                match parser.get_round_range() {
-                    Some((min_round, max_round)) => {
+                match engine.round_range() {
+                    Some(range) => {
                         println!("현재 저장된 회차: {}회 ~ {}회 (총 {}개)",
-                                min_round, max_round, parser.drawings.len());
+                                range.min, range.max, range.count);
                     }
                     None => {
                         println!("현재 저장된 데이터가 없습니다.");
@@ -488,7 +609,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let mut numbers_input = String::new();
                 std::io::stdin().read_line(&mut numbers_input)?;
                 let number_parts: Vec<u8> = numbers_input
-                    .trim()
                     .split_whitespace()
                     .map(|s| s.parse().unwrap())
                     .collect();
@@ -506,25 +626,72 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 std::io::stdin().read_line(&mut bonus_input)?;
                 let bonus = bonus_input.trim().parse::<u8>()?;
 
-                parser.add_new_drawing(round, numbers, bonus);
-                parser.save_to_text_file("lottery_data.txt")?;
+                let added = LotteryDrawing::new(round, numbers, bonus)
+                    .and_then(|drawing| engine.add_drawing(drawing));
+                if let Err(error) = added {
+                    println!("오류: {}", error);
+                    show_menu();
+                    continue;
+                }
+                save_to_text_file(engine.drawings(), "lottery_data.txt")?;
 
                 println!("{}회차 데이터가 추가되었습니다.", round);
 
                 // 업데이트된 회차 범위 표시
-                if let Some((min_round, max_round)) = parser.get_round_range() {
+                if let Some(range) = engine.round_range() {
                     println!("업데이트된 회차: {}회 ~ {}회 (총 {}개)",
-                            min_round, max_round, parser.drawings.len());
+                            range.min, range.max, range.count);
                 }
 
                 show_menu();
             }
             "5" => {
+                check_tickets_menu(&engine)?;
+                show_menu();
+            }
+            "6" => {
+                backtest_menu(&engine, fixed_seed)?;
+                show_menu();
+            }
+            "7" => {
+                expected_value_menu(&engine)?;
+                show_menu();
+            }
+            "8" => {
+                let filter_config = filter_config.unwrap_or_default();
+                recommend_menu(&engine, &exclusion, &filter_config, fixed_seed)?;
+                show_menu();
+            }
+            "9" => {
+                wheel_menu(&engine, &exclusion, fixed_seed)?;
+                show_menu();
+            }
+            "10" => {
+                gap_menu(&engine)?;
+                show_menu();
+            }
+            "11" => {
+                cooccurrence_menu(&engine)?;
+                show_menu();
+            }
+            "12" => {
+                randomness_menu(&engine)?;
+                show_menu();
+            }
+            "13" => {
+                distribution_menu(&engine, &filter_config.unwrap_or_default())?;
+                show_menu();
+            }
+            "14" => {
+                simulation_menu(&engine, &exclusion, fixed_seed)?;
+                show_menu();
+            }
+            "15" => {
                 println!("프로그램을 종료합니다.");
                 break;
             }
             _ => {
-                println!("올바른 번호를 입력해주세요 (1-5).");
+                println!("올바른 번호를 입력해주세요 (1-15).");
                 show_menu();
             }
         }
@@ -532,3 +699,741 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     Ok(())
 }
+
+fn backtest_menu(engine: &LotteryEngine, fixed_seed: Option<u64>) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 백테스트 (전략 비교) ===");
+
+    let range = match engine.round_range() {
+        Some(range) => range,
+        None => {
+            println!("현재 저장된 데이터가 없습니다.");
+            return Ok(());
+        }
+    };
+
+    // 기본: 최근 100회
+    let default_from = range.max.saturating_sub(99).max(range.min + 1);
+    let from_round = read_input(&format!("시작 회차 (Enter: {}회): ", default_from))?;
+    let to_round = read_input(&format!("종료 회차 (Enter: {}회): ", range.max))?;
+    let (from_round, to_round) = match (parse_or(&from_round, default_from), parse_or(&to_round, range.max)) {
+        (Some(from_round), Some(to_round)) => (from_round, to_round),
+        _ => {
+            println!("올바른 회차를 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let required = match get_number_input("필수 번호 전략에 넣을 번호들 (공백으로 구분, Enter: 비교 안 함): ") {
+        Ok(required) => required,
+        Err(_) => {
+            println!("올바른 번호를 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let seed = fixed_seed.unwrap_or_else(random_seed);
+    let mut config = BacktestConfig::new(from_round, to_round);
+    config.seed = Some(seed);
+
+    let mut strategies: Vec<Box<dyn Strategy>> = vec![
+        Box::new(UniformStrategy { exclude_past: true }),
+        Box::new(UniformStrategy { exclude_past: false }),
+    ];
+    if !required.is_empty() {
+        strategies.push(Box::new(RequiredStrategy { required }));
+    }
+
+    println!("\n{}회 ~ {}회, 회차당 {}장 (시드: {})", from_round, to_round, config.tickets_per_round, seed);
+    println!("{:<22}{:>6}{:>6}{:>6}{:>6}{:>6}{:>14}{:>14}{:>9}",
+             "전략", "1등", "2등", "3등", "4등", "5등", "구매액", "당첨금", "회수율");
+
+    for strategy in &strategies {
+        match engine.backtest(strategy.as_ref(), &config) {
+            Ok(report) => {
+                println!("{:<22}{:>6}{:>6}{:>6}{:>6}{:>6}{:>14}{:>14}{:>8.1}%",
+                         strategy.name(),
+                         report.hits_for(PrizeTier::First),
+                         report.hits_for(PrizeTier::Second),
+                         report.hits_for(PrizeTier::Third),
+                         report.hits_for(PrizeTier::Fourth),
+                         report.hits_for(PrizeTier::Fifth),
+                         report.cost,
+                         report.winnings,
+                         report.return_rate * 100.0);
+            }
+            Err(error) => {
+                println!("오류: {}", error);
+            }
+        }
+    }
+    println!("(1-3등 당첨금은 평균값 기준)");
+
+    Ok(())
+}
+
+fn expected_value_menu(engine: &LotteryEngine) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 기대값 계산 (티켓 1장) ===");
+
+    // 가장 최근 회차의 당첨 결과를 기본 예상치로 사용
+    let mut params = match load_round_prizes("static") {
+        Ok(prizes) => match prizes.last() {
+            Some(latest) => {
+                println!("기준: {}회 당첨 결과 (static 엑셀 파일)", latest.round);
+                EvParams::from_round_prizes(latest)
+            }
+            None => EvParams::default(),
+        },
+        Err(error) => {
+            println!("당첨금 정보를 읽지 못해 기본값을 사용합니다: {}", error);
+            EvParams::default()
+        }
+    };
+
+    let pool = read_input(&format!("1등 예상 총 당첨금 (이월금 포함, Enter: {}원): ", params.first.pool))?;
+    let co_winners = read_input(&format!("1등 예상 당첨자 수 (본인 제외, Enter: {}명): ", params.first.co_winners))?;
+    match (parse_or(&pool, params.first.pool), parse_or(&co_winners, params.first.co_winners)) {
+        (Some(pool), Some(co_winners)) => {
+            params.first.pool = pool;
+            params.first.co_winners = co_winners;
+        }
+        _ => {
+            println!("올바른 값을 입력해주세요.");
+            return Ok(());
+        }
+    }
+
+    let report = match engine.expected_value(&params) {
+        Ok(report) => report,
+        Err(error) => {
+            println!("오류: {}", error);
+            return Ok(());
+        }
+    };
+
+    println!("\n{:<6}{:>16}{:>16}{:>16}{:>10}{:>10}",
+             "등수", "확률", "당첨금(세전)", "당첨금(세후)", "기대값", "세후");
+    for tier in &report.tiers {
+        println!("{:<6}{:>16}{:>16.0}{:>16.0}{:>10.1}{:>10.1}",
+                 tier.tier.to_string(),
+                 format!("1/{:.0}", 1.0 / tier.probability),
+                 tier.gross_prize,
+                 tier.net_prize,
+                 tier.gross_ev,
+                 tier.net_ev);
+    }
+
+    println!("\n티켓 가격: {}원", report.ticket_price);
+    println!("기대 당첨금: 세전 {:.1}원 / 세후 {:.1}원", report.gross_return, report.net_return);
+    println!("기대 손익: 세전 {:+.1}원 / 세후 {:+.1}원", report.gross_ev, report.net_ev);
+    println!("회수율: 세전 {:.1}% / 세후 {:.1}%", report.gross_return_rate * 100.0, report.net_return_rate * 100.0);
+    if report.is_favorable() {
+        println!("(세후 기대값이 티켓 가격보다 큽니다)");
+    } else {
+        println!("(세후 기대값이 티켓 가격보다 작습니다)");
+    }
+
+    Ok(())
+}
+
+fn recommend_menu(
+    engine: &LotteryEngine,
+    exclusion: &ExclusionPolicy,
+    filter_config: &FilterConfig,
+    fixed_seed: Option<u64>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 점수 기반 추천 ===");
+
+    let count = read_input("추천 개수 (Enter: 10개): ")?;
+    let count = match parse_or(&count, 10usize) {
+        Some(count) if count > 0 => count,
+        _ => {
+            println!("올바른 개수를 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let config = RecommenderConfig::default();
+    let filter = engine.filters(filter_config);
+    let seed = fixed_seed.unwrap_or_else(random_seed);
+
+    let diversity = DiversityConfig::default();
+    let selection = match engine.recommend_diverse(&config, &diversity, exclusion, &filter, count, Some(seed)) {
+        Ok(selection) => selection,
+        Err(error) => {
+            println!("오류: {}", error);
+            return Ok(());
+        }
+    };
+    let tickets = &selection.tickets;
+
+    if let Some(latest) = engine.drawings().last() {
+        println!("[{}회 당첨번호] {:?} + 보너스 {}", latest.round, latest.sorted_numbers(), latest.bonus);
+    }
+    println!("\n{:<4}{:<24}{:>6}{:>6}{:>6}{:>6}{:>8}", "No.", "조합", "합계", "홀짝", "저고", "연속", "점수");
+    for (i, ticket) in tickets.iter().enumerate() {
+        let numbers = ticket.numbers;
+        let sum: u32 = numbers.iter().map(|&n| n as u32).sum();
+        let odd = numbers.iter().filter(|&&n| n % 2 == 1).count();
+        let low = numbers.iter().filter(|&&n| n <= 22).count();
+        let combo = numbers.iter().map(|n| format!("{:2}", n)).collect::<Vec<String>>().join("-");
+
+        println!("{:<4}{:<24}{:>6}{:>6}{:>6}{:>6}{:>8.3}",
+                 i + 1, combo, sum, format!("{}:{}", odd, 6 - odd), format!("{}:{}", low, 6 - low),
+                 consecutive_pairs(&numbers), ticket.score);
+    }
+
+    let stats = &selection.stats;
+    println!("\n[다양성 통계] 사용된 고유 번호: {}/{}", stats.unique_numbers, engine.game().pool);
+    println!("  번호별 등장: {}", stats.number_counts
+        .iter()
+        .map(|(number, count)| format!("{}({})", number, count))
+        .collect::<Vec<String>>()
+        .join(", "));
+
+    println!("\n가중치: 전체 빈도 {}, 최근 {}회 {}, 갭 {} (온도 {})",
+             config.frequency_weight, config.recent_window, config.recent_weight, config.gap_weight, config.temperature);
+    println!("다양성: 번호 재사용 감점 {}, 번호당 최대 {}장 (상위 {}개 후보에서 선택)",
+             diversity.lambda, diversity.max_per_number, diversity.pool_size);
+    println!("필터: {}", filter.name());
+    println!("({})", exclusion);
+    println!("시드: {} (--seed {}로 재현 가능)", seed, seed);
+
+    Ok(())
+}
+
+fn wheel_menu(engine: &LotteryEngine, exclusion: &ExclusionPolicy, fixed_seed: Option<u64>) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 휠링 (조합 보장) ===");
+
+    let (pool, keys) = match (
+        get_number_input(&format!("풀 번호 (최대 {}개, 공백으로 구분): ", MAX_WHEEL_POOL)),
+        get_number_input("키 번호 - 모든 티켓에 포함 (Enter: 없음): "),
+    ) {
+        (Ok(pool), Ok(keys)) => (pool, keys),
+        _ => {
+            println!("올바른 번호를 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let guarantee = read_input("보장 조건 '추첨 수 일치 수' (예: 4 3 = 풀에서 4개가 나오면 3개 일치, Enter: 전체 휠): ")?;
+    let guarantee: Vec<u8> = match guarantee.split_whitespace().map(|s| s.parse()).collect() {
+        Ok(values) => values,
+        Err(_) => {
+            println!("올바른 보장 조건을 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let mut search = None;
+    let wheel = match guarantee.as_slice() {
+        [] => engine.full_wheel(&pool, &keys, exclusion),
+        &[if_drawn, matched] => {
+            let guarantee = WheelGuarantee { if_drawn, matched };
+            let seconds = read_input("더 적은 티켓 탐색 시간 (초, Enter: 탐색 안 함): ")?;
+            match parse_or(&seconds, 0.0f64) {
+                Some(seconds) if seconds > 0.0 => {
+                    let seed = fixed_seed.unwrap_or_else(random_seed);
+                    let config = CoveringConfig {
+                        time_budget_ms: (seconds * 1000.0) as u64,
+                        seed: Some(seed),
+                        ..CoveringConfig::default()
+                    };
+                    println!("탐색 중...");
+                    engine.search_covering(&pool, &keys, guarantee, exclusion, &config).map(|result| {
+                        search = Some((result.initial_tickets, result.iterations, result.elapsed_ms, seed));
+                        result.wheel
+                    })
+                }
+                Some(_) => engine.abbreviated_wheel(&pool, &keys, guarantee, exclusion),
+                None => {
+                    println!("올바른 시간을 입력해주세요.");
+                    return Ok(());
+                }
+            }
+        }
+        _ => {
+            println!("올바른 보장 조건을 입력해주세요.");
+            return Ok(());
+        }
+    };
+    let wheel = match wheel {
+        Ok(wheel) => wheel,
+        Err(error) => {
+            println!("오류: {}", error);
+            return Ok(());
+        }
+    };
+
+    println!();
+    for (i, ticket) in wheel.tickets.iter().enumerate() {
+        println!("{:>4}. {:?}", i + 1, ticket);
+    }
+
+    let WheelGuarantee { if_drawn, matched } = wheel.guarantee;
+    println!("\n풀: {:?}", wheel.pool);
+    if wheel.keys.is_empty() {
+        println!("보장: 풀에서 {}개가 나오면 최소 {}개 일치", if_drawn, matched);
+    } else {
+        println!("키 번호: {:?} (모든 티켓에 포함)", wheel.keys);
+        println!("보장: 키 번호가 모두 나오고 풀에서 {}개가 나오면 최소 {}개 일치 (키 포함)",
+                 if_drawn, matched as usize + wheel.keys.len());
+    }
+    println!("검증: {}/{} 경우 만족 ({:.2}%)", wheel.check.covered, wheel.check.cases, wheel.check.coverage);
+    println!("티켓 수: {}장, 비용: {}원", wheel.tickets.len(), wheel.cost);
+    if wheel.excluded > 0 {
+        println!("제외 정책으로 빠진 티켓: {}장", wheel.excluded);
+    }
+    if let Some((initial_tickets, iterations, elapsed_ms, seed)) = search {
+        println!("탐색: 축약 휠 {}장 → {}장 (이동 {}회, {:.1}초, 시드 {})",
+                 initial_tickets, wheel.tickets.len(), iterations, elapsed_ms as f64 / 1000.0, seed);
+    }
+    println!("({})", exclusion);
+
+    Ok(())
+}
+
+fn frequency_menu(engine: &LotteryEngine) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 빈도 분석 ===");
+
+    let window = read_input("분석 구간 (Enter: 전체, 100: 최근 100회, 900-1000: 회차 범위): ")?;
+    let sort_by = read_input("정렬 기준 (Enter: 합계, m: 본번호, b: 보너스): ")?;
+    let order = read_input("정렬 방향 (Enter: 적은 순, d: 많은 순): ")?;
+
+    let window = if window.is_empty() { Ok(RoundWindow::All) } else { window.parse() };
+    let sort_by = match sort_by.to_lowercase().as_str() {
+        "" => Some(FrequencyKind::Combined),
+        "m" => Some(FrequencyKind::Main),
+        "b" => Some(FrequencyKind::Bonus),
+        _ => None,
+    };
+    let order = match order.to_lowercase().as_str() {
+        "" => Some(SortOrder::Ascending),
+        "d" => Some(SortOrder::Descending),
+        _ => None,
+    };
+    let query = match (window, sort_by, order) {
+        (Ok(window), Some(sort_by), Some(order)) => FrequencyQuery { window, sort_by, order },
+        _ => {
+            println!("올바른 조건을 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let report = match engine.frequency_report(&query) {
+        Ok(report) => report,
+        Err(error) => {
+            println!("오류: {}", error);
+            return Ok(());
+        }
+    };
+
+    println!("\n{}회 ~ {}회 ({}회 추첨)", report.from_round, report.to_round, report.draws);
+    println!("{:<6}{:>4}{:>8}{:>8}{:>8}{:>10}{:>8}", "순위", "번호", "본번호", "보너스", "합계", "편차", "z");
+    for (i, frequency) in report.numbers.iter().enumerate() {
+        let combined = &frequency.combined;
+        println!("{:<6}{:>4}{:>8}{:>8}{:>8}{:>+10.1}{:>+8.2}",
+                 i + 1, frequency.number, frequency.main.count, frequency.bonus.count,
+                 combined.count, combined.deviation, combined.z_score);
+    }
+
+    if let Some(first) = report.numbers.first() {
+        println!("\n기대 횟수: 본번호 {:.1}회, 보너스 {:.1}회, 합계 {:.1}회 (편차, z는 합계 기준)",
+                 first.main.expected, first.bonus.expected, first.combined.expected);
+    }
+
+    loop {
+        let input = read_input("\n번호 조회 (Enter: 종료): ")?;
+        if input.is_empty() {
+            break;
+        }
+
+        match input.parse().ok().and_then(|number| report.number(number)) {
+            Some(frequency) => {
+                println!("{}번 ({}회 ~ {}회, {}회 추첨)", frequency.number, report.from_round, report.to_round, report.draws);
+                for (name, stats) in [("본번호", &frequency.main), ("보너스", &frequency.bonus), ("합계", &frequency.combined)] {
+                    println!("  {}: {}회 (기대 {:.1}회, 편차 {:+.1}, z {:+.2})",
+                             name, stats.count, stats.expected, stats.deviation, stats.z_score);
+                }
+            }
+            None => {
+                println!("1-{} 사이의 번호를 입력해주세요.", engine.game().pool);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn cooccurrence_menu(engine: &LotteryEngine) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 동시출현 분석 ===");
+
+    let window = read_input("분석 구간 (Enter: 전체, 100: 최근 100회, 900-1000: 회차 범위): ")?;
+    let triples = read_input("삼중 동시출현도 셀까요? (y/N): ")?;
+    let top = read_input("목록 길이 (Enter: 10): ")?;
+
+    let window = if window.is_empty() { Ok(RoundWindow::All) } else { window.parse() };
+    let query = match (window, parse_or(&top, 10)) {
+        (Ok(window), Some(top)) => CooccurrenceQuery {
+            window,
+            triples: triples.eq_ignore_ascii_case("y"),
+            top,
+        },
+        _ => {
+            println!("올바른 조건을 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let report = match engine.cooccurrence_report(&query) {
+        Ok(report) => report,
+        Err(error) => {
+            println!("오류: {}", error);
+            return Ok(());
+        }
+    };
+
+    println!("\n{}회 ~ {}회 ({}회 추첨), 쌍 하나의 기대 횟수 {:.1}회", report.from_round, report.to_round, report.draws, report.pair_expected);
+    for (title, pairs) in [("많이 나온 쌍", &report.most_common_pairs), ("적게 나온 쌍 (1회 이상)", &report.least_common_pairs)] {
+        println!("\n{}", title);
+        for pair in pairs {
+            println!("  {:>2} - {:>2}: {:>3}회 (편차 {:+.1}, z {:+.2})",
+                     pair.numbers[0], pair.numbers[1], pair.stats.count, pair.stats.deviation, pair.stats.z_score);
+        }
+    }
+
+    if report.never_paired.is_empty() {
+        println!("\n한 번도 함께 나오지 않은 쌍: 없음");
+    } else {
+        // 짧은 구간에서는 수백 개가 되므로 앞부분만 출력
+        const SHOWN: usize = 30;
+        let never = &report.never_paired;
+        let shown: Vec<String> = never.iter().take(SHOWN).map(|[a, b]| format!("{}-{}", a, b)).collect();
+        let rest = if never.len() > SHOWN { format!(" 외 {}개", never.len() - SHOWN) } else { String::new() };
+        println!("\n한 번도 함께 나오지 않은 쌍 ({}개): {}{}", never.len(), shown.join(", "), rest);
+    }
+
+    if let Some(triples) = &report.triples {
+        println!("\n많이 나온 삼중 (기대 {:.2}회)", triples.expected);
+        for triple in &triples.most_common {
+            println!("  {:>2} - {:>2} - {:>2}: {:>3}회 (z {:+.2})",
+                     triple.numbers[0], triple.numbers[1], triple.numbers[2], triple.stats.count, triple.stats.z_score);
+        }
+        println!("한 번도 함께 나오지 않은 삼중: {} / {}개", triples.never_appeared, triples.total);
+    }
+
+    loop {
+        let input = read_input("\n쌍 조회 (예: 7 23, Enter: 종료): ")?;
+        if input.is_empty() {
+            break;
+        }
+
+        let numbers: Vec<u8> = input.split_whitespace().filter_map(|n| n.parse().ok()).collect();
+        let pool = engine.game().pool;
+        match numbers.as_slice() {
+            &[a, b] if a != b && (1..=pool).contains(&a) && (1..=pool).contains(&b) => {
+                println!("{}-{}: {}회 함께 출현 (기대 {:.1}회)", a, b, report.pair_count(a, b), report.pair_expected);
+            }
+            _ => {
+                println!("1-{} 사이의 서로 다른 번호 두 개를 입력해주세요.", pool);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn randomness_menu(engine: &LotteryEngine) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 무작위성 검정 ===");
+
+    let window = read_input("분석 구간 (Enter: 전체, 100: 최근 100회, 900-1000: 회차 범위): ")?;
+    let window = if window.is_empty() { Ok(RoundWindow::All) } else { window.parse() };
+    let report = match window.and_then(|window| engine.randomness_report(window)) {
+        Ok(report) => report,
+        Err(error) => {
+            println!("오류: {}", error);
+            return Ok(());
+        }
+    };
+
+    println!("\n{}회 ~ {}회 ({}회 추첨), 가설: 매 회차 균등·독립 추첨", report.from_round, report.to_round, report.draws);
+    println!("{:<16}{:>12}{:>10}  판정 (유의수준 5%)", "검정", "통계량", "p값");
+    let statistics = [
+        format!("χ²={:.2}", report.frequency.statistic),
+        format!("z={:+.2}", report.runs.z_score),
+        format!("r={:+.3}", report.serial.correlation),
+        format!("χ²={:.2}", report.gap.statistic),
+        format!("D={:.4}", report.sum.statistic),
+    ];
+    for ((name, p_value), statistic) in report.p_values().into_iter().zip(&statistics) {
+        let verdict = if p_value < 0.05 { "균등 가설 기각" } else { "이상 없음" };
+        println!("{:<16}{:>12}{:>10.4}  {}", name, statistic, p_value, verdict);
+    }
+
+    let frequency = &report.frequency;
+    println!("\n빈도 카이제곱: 번호당 기대 {:.1}회, 최소 {}회, 최대 {}회 (자유도 {})",
+             frequency.expected, frequency.min_count, frequency.max_count, frequency.degrees_of_freedom);
+    let runs = &report.runs;
+    println!("홀짝 런: 홀수 우세 {}회, 짝수 우세 {}회 (3:3 {}회 제외), 런 {}개 (기대 {:.1}개)",
+             runs.odd_heavy, runs.even_heavy, runs.ties, runs.runs, runs.expected_runs);
+    println!("합계 계열 상관: 연속 회차 합계의 1차 자기상관 {:+.3} (z {:+.2})", report.serial.correlation, report.serial.z_score);
+    println!("갭: {}개 갭을 {}개 구간으로 비교 (자유도 {})", report.gap.gaps, report.gap.bins.len(), report.gap.degrees_of_freedom);
+    println!("합계 KS: 평균 합계 {:.1} (이론 {:.1})", report.sum.observed_mean, report.sum.expected_mean);
+    println!("※ 검정 5개를 함께 보므로 하나쯤은 우연히 5% 미만이 나올 수 있습니다.");
+
+    let path = read_input("\nJSON으로 저장할 파일 (Enter: 건너뜀): ")?;
+    if !path.is_empty() {
+        std::fs::write(&path, serde_json::to_string_pretty(&report)?)?;
+        println!("{}에 저장했습니다.", path);
+    }
+
+    Ok(())
+}
+
+fn distribution_menu(engine: &LotteryEngine, filter_config: &FilterConfig) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 이론 분포 비교 ===");
+
+    let window = read_input("분석 구간 (Enter: 전체, 100: 최근 100회, 900-1000: 회차 범위): ")?;
+    let window = if window.is_empty() { Ok(RoundWindow::All) } else { window.parse() };
+    println!("필터 통과 조합을 세는 중...");
+    let result = window.and_then(|window| Ok((engine.distribution_report(window)?, engine.filter_coverage(filter_config, window)?)));
+    let (report, coverage) = match result {
+        Ok(result) => result,
+        Err(error) => {
+            println!("오류: {}", error);
+            return Ok(());
+        }
+    };
+
+    println!("\n{}회 ~ {}회 ({}회 추첨), 전체 조합 {}개 기준 이론 분포", report.from_round, report.to_round, report.draws, report.total_combinations);
+    for distribution in &report.features {
+        let label = match distribution.feature {
+            Feature::LowCount => format!("{} ({} 이하)", distribution.feature, engine.game().pool / 2),
+            Feature::DecadeSpread => format!("{} (1-9, 10-19, ...)", distribution.feature),
+            feature => feature.to_string(),
+        };
+        println!("\n[{}] 이론 평균 {:.2}, 실제 평균 {:.2}", label, distribution.mean, distribution.observed_mean);
+        println!("{:>10}{:>10}{:>10}{:>10}{:>10}", "값", "이론 비율", "기대 회차", "실제 회차", "실제 비율");
+
+        // 합계와 최대 간격은 값이 많아 묶어서 출력
+        let width = match distribution.feature {
+            Feature::Sum => 10,
+            Feature::MaxGap => 5,
+            _ => 1,
+        };
+        let (Some(first), Some(last)) = (distribution.buckets.first(), distribution.buckets.last()) else {
+            continue;
+        };
+        let mut from = first.value / width * width;
+        while from <= last.value {
+            let to = from + width - 1;
+            let probability = distribution.probability_between(from, to);
+            let observed = distribution.observed_between(from, to);
+            if probability > 0.0 {
+                let value = if width == 1 { from.to_string() } else { format!("{}-{}", from, to) };
+                println!("{:>10}{:>9.2}%{:>10.1}{:>10}{:>9.2}%",
+                         value, probability * 100.0, probability * report.draws as f64,
+                         observed, observed as f64 / report.draws as f64 * 100.0);
+            }
+            from += width;
+        }
+    }
+
+    println!("\n[필터가 남기는 비율] (겹침 조건은 마지막 회차 기준, 실제 비율은 각 회차의 직전 회차 기준)");
+    println!("{:<24}{:>10}{:>12}", "조건", "조합", "실제 당첨");
+    for condition in &coverage.conditions {
+        println!("{:<24}{:>9.2}%{:>11.2}%", condition.name, condition.kept * 100.0, condition.observed_kept * 100.0);
+    }
+    println!("{:<24}{:>9.2}%{:>11.2}%  ({} / {}개 조합)", "모든 조건", coverage.kept * 100.0, coverage.observed_kept * 100.0,
+             coverage.kept_combinations, coverage.total_combinations);
+
+    Ok(())
+}
+
+fn gap_menu(engine: &LotteryEngine) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 미출현 갭 분석 ===");
+
+    let percentile = read_input("과출 기준 백분위수 (Enter: 90): ")?;
+    let report = match parse_or(&percentile, 90.0) {
+        Some(percentile) => match engine.gap_report(percentile) {
+            Ok(report) => report,
+            Err(error) => {
+                println!("오류: {}", error);
+                return Ok(());
+            }
+        },
+        None => {
+            println!("올바른 숫자를 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let label = format!("p{}", report.percentile);
+    let or_dash = |value: Option<usize>| value.map_or("-".to_string(), |value| value.to_string());
+
+    println!("\n{}회 기준 ({}회 추첨), 갭 = 나오지 않은 회차 수", report.latest_round, report.draws);
+    println!("{:>4}{:>8}{:>8}{:>8}{:>8}{:>12}{:>12}  상태",
+             "번호", "현재", "최장", "평균", label, "보너스 현재", "보너스 최장");
+    for gap in &report.numbers {
+        let main = &gap.main;
+        let status = match (main.overdue, gap.bonus.overdue) {
+            (true, true) => "본번호·보너스 과출",
+            (true, false) => "본번호 과출",
+            (false, true) => "보너스 과출",
+            (false, false) => "",
+        };
+        println!("{:>4}{:>8}{:>8}{:>8.1}{:>8}{:>12}{:>12}  {}",
+                 gap.number, main.current, or_dash(main.longest), main.average.unwrap_or(0.0),
+                 or_dash(main.threshold), gap.bonus.current, or_dash(gap.bonus.longest), status);
+    }
+
+    let join = |numbers: &[u8]| numbers.iter().map(|n| n.to_string()).collect::<Vec<String>>().join(", ");
+    println!("\n본번호 과출 (현재 갭 > 과거 갭 {}): {}", label, join(&report.overdue_main));
+    println!("보너스 과출 (현재 갭 > 과거 갭 {}): {}", label, join(&report.overdue_bonus));
+
+    loop {
+        let input = read_input("\n갭 분포 조회할 번호 (Enter: 종료): ")?;
+        if input.is_empty() {
+            break;
+        }
+
+        match input.parse().ok().and_then(|number| report.number(number)) {
+            Some(gap) => {
+                for (name, stats) in [("본번호", &gap.main), ("보너스", &gap.bonus)] {
+                    let last = stats.last_round.map_or("없음".to_string(), |round| format!("{}회", round));
+                    println!("{}번 {}: {}회 출현, 마지막 {}, 현재 갭 {}", gap.number, name, stats.appearances, last, stats.current);
+                    println!("  분포 (갭:횟수) {}", stats.distribution
+                        .iter()
+                        .map(|(gap, count)| format!("{}:{}", gap, count))
+                        .collect::<Vec<String>>()
+                        .join(" "));
+                }
+            }
+            None => {
+                println!("1-{} 사이의 번호를 입력해주세요.", engine.game().pool);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn simulation_menu(engine: &LotteryEngine, exclusion: &ExclusionPolicy, fixed_seed: Option<u64>) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== 장기 구매 시뮬레이션 ===");
+
+    let kind = read_input("전략 (1: 자동, 2: 고정 번호, 3: 반자동 포함/제외, Enter: 1): ")?;
+    let strategy = match kind.as_str() {
+        "" | "1" | "3" => {
+            let constraints = if kind == "3" {
+                match read_constraints() {
+                    Ok(constraints) => constraints,
+                    Err(_) => {
+                        println!("올바른 번호를 입력해주세요.");
+                        return Ok(());
+                    }
+                }
+            } else {
+                NumberConstraints::default()
+            };
+            let count = read_input("주당 티켓 수 (Enter: 5장): ")?;
+            let Some(count) = parse_or(&count, 5usize) else {
+                println!("올바른 값을 입력해주세요.");
+                return Ok(());
+            };
+            if constraints.is_empty() {
+                PlayStrategy::QuickPick { count }
+            } else {
+                PlayStrategy::Constrained { constraints, count }
+            }
+        }
+        "2" => {
+            println!("매주 살 번호 6개씩 한 줄에 입력하세요 (빈 줄: 입력 완료)");
+            let mut tickets = Vec::new();
+            loop {
+                match get_number_input(&format!("{}: ", tickets.len() + 1)) {
+                    Ok(numbers) if numbers.is_empty() => break,
+                    Ok(numbers) => match <[u8; 6]>::try_from(numbers) {
+                        Ok(ticket) => tickets.push(ticket),
+                        Err(_) => println!("6개의 번호를 입력해주세요."),
+                    },
+                    Err(_) => println!("올바른 번호를 입력해주세요."),
+                }
+            }
+            PlayStrategy::Fixed { tickets }
+        }
+        _ => {
+            println!("올바른 번호를 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let source = match read_input("추첨 방식 (1: 균등 추출, 2: 실제 회차 복원 추출, Enter: 1): ")?.as_str() {
+        "" | "1" => DrawSource::Synthetic,
+        "2" => DrawSource::Resample,
+        _ => {
+            println!("올바른 번호를 입력해주세요.");
+            return Ok(());
+        }
+    };
+
+    let mut config = SimulationConfig::new(strategy, source);
+    config.runs = 1_000;
+    let weeks = read_input(&format!("구매 주 수 (Enter: {}주 = 10년): ", config.weeks))?;
+    let runs = read_input(&format!("반복 횟수 (Enter: {}회): ", config.runs))?;
+    let budget = read_input("시작 예산 (원, Enter: 파산 위험 계산 안 함): ")?;
+    match (parse_or(&weeks, config.weeks), parse_or(&runs, config.runs), parse_or(&budget, 0u64)) {
+        (Some(weeks), Some(runs), Some(budget)) => {
+            config.weeks = weeks;
+            config.runs = runs;
+            config.budget = (budget > 0).then_some(budget);
+        }
+        _ => {
+            println!("올바른 값을 입력해주세요.");
+            return Ok(());
+        }
+    }
+    config.seed = Some(fixed_seed.unwrap_or_else(random_seed));
+
+    println!("시뮬레이션 중...");
+    let report = match engine.simulate(&config, exclusion) {
+        Ok(report) => report,
+        Err(error) => {
+            println!("오류: {}", error);
+            return Ok(());
+        }
+    };
+
+    let source = match report.source {
+        DrawSource::Synthetic => "균등 추출",
+        DrawSource::Resample => "실제 회차 복원 추출",
+    };
+    println!("\n{}, {}주 × {}회 반복, {} (시드: {})", report.strategy, report.weeks, report.runs, source, report.seed);
+    println!("반복당 구매액: {}원 (주당 {}장)", report.cost, report.tickets_per_week);
+
+    let net = &report.net;
+    println!("\n순손익 분포:");
+    println!("  평균 {:+.0}원 (표준편차 {:.0}원), 회수율 {:.1}%", net.mean, net.std_dev, net.return_rate * 100.0);
+    for (level, value) in &net.percentiles {
+        println!("  하위 {:>2}%: {:+}원", level, value);
+    }
+    println!("  최소 {:+}원 / 최대 {:+}원", net.min, net.max);
+    println!("  이익으로 끝난 비율: {:.2}%", net.profitable * 100.0);
+
+    if let Some(ruin) = &report.ruin {
+        print!("\n예산 {}원 파산 위험: {:.2}%", ruin.budget, ruin.probability * 100.0);
+        match ruin.median_week {
+            Some(week) => println!(" (파산 주 중앙값 {}주차)", week),
+            None => println!(),
+        }
+    }
+
+    let streak = &report.losing_streak;
+    println!("\n최장 연속 낙첨: 평균 {:.1}주, 중앙값 {}주, 최대 {}주", streak.mean, streak.median, streak.max);
+
+    println!("\n{:<6}{:>16}{:>16}", "등수", "한 번 이상", "평균 횟수");
+    for tier in &report.tiers {
+        println!("{:<6}{:>15.2}%{:>16.3}", tier.tier.to_string(), tier.ever * 100.0, tier.mean_hits);
+    }
+    println!("(1-3등 당첨금은 평균값 기준, 순손익은 파산과 관계없이 끝까지 구매했다고 가정)");
+
+    Ok(())
+}
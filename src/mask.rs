@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use std::ops::{BitAnd, BitOr};
+
+use crate::{LotteryDrawing, LotteryError};
+
+/// 마스크로 표현할 수 있는 가장 큰 번호
+pub const MAX_MASK_NUMBER: u8 = 63;
+
+/// 번호 n이면 n번째 비트가 켜지는 티켓 표현 (1-63 번호용)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TicketMask(pub u64);
+
+impl TicketMask {
+    pub const EMPTY: TicketMask = TicketMask(0);
+
+    /// 번호 목록으로 마스크 생성 (1-63 범위 전제, 외부 입력은 try_from 사용)
+    pub(crate) fn from_numbers(numbers: &[u8]) -> TicketMask {
+        debug_assert!(numbers.iter().all(|&n| (1..=MAX_MASK_NUMBER).contains(&n)));
+        TicketMask(numbers.iter().fold(0, |mask, &n| mask | 1u64 << n))
+    }
+
+    /// 번호 목록을 검사해 마스크 생성 (1-63 범위, 중복 불가)
+    pub fn checked(numbers: &[u8]) -> Result<TicketMask, LotteryError> {
+        let mut mask = 0u64;
+        for &num in numbers {
+            if !(1..=MAX_MASK_NUMBER).contains(&num) {
+                return Err(LotteryError::NumberOutOfRange { number: num, min: 1, max: MAX_MASK_NUMBER });
+            }
+            if mask & 1u64 << num != 0 {
+                return Err(LotteryError::DuplicateNumber(num));
+            }
+            mask |= 1u64 << num;
+        }
+        Ok(TicketMask(mask))
+    }
+
+    /// 6개 번호 티켓으로 변환 (정확히 6개가 아니면 None)
+    pub fn to_ticket(self) -> Option<[u8; 6]> {
+        if self.len() != 6 {
+            return None;
+        }
+
+        let mut ticket = [0u8; 6];
+        for (slot, num) in ticket.iter_mut().zip(self.iter()) {
+            *slot = num;
+        }
+        Some(ticket)
+    }
+
+    /// 오름차순 번호
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let num = bits.trailing_zeros() as u8;
+            bits &= bits - 1;
+            Some(num)
+        })
+    }
+
+    /// 번호 개수
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, num: u8) -> bool {
+        num < 64 && self.0 & (1u64 << num) != 0
+    }
+
+    /// 추첨 보너스 번호가 티켓에 있는지 여부 (2등 판정용)
+    pub fn contains_bonus(self, bonus: u8) -> bool {
+        self.contains(bonus)
+    }
+
+    /// 다른 티켓과 겹치는 번호 개수
+    pub fn matches(self, other: TicketMask) -> u32 {
+        (self.0 & other.0).count_ones()
+    }
+
+    pub fn union(self, other: TicketMask) -> TicketMask {
+        TicketMask(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: TicketMask) -> TicketMask {
+        TicketMask(self.0 & other.0)
+    }
+
+    /// 번호 하나를 추가한 마스크 (1-63 범위 전제)
+    pub(crate) fn with(self, num: u8) -> TicketMask {
+        TicketMask(self.0 | 1u64 << num)
+    }
+
+    /// 번호 하나를 뺀 마스크 (1-63 범위 전제)
+    pub(crate) fn without(self, num: u8) -> TicketMask {
+        TicketMask(self.0 & !(1u64 << num))
+    }
+}
+
+impl BitOr for TicketMask {
+    type Output = TicketMask;
+
+    fn bitor(self, other: TicketMask) -> TicketMask {
+        self.union(other)
+    }
+}
+
+impl BitAnd for TicketMask {
+    type Output = TicketMask;
+
+    fn bitand(self, other: TicketMask) -> TicketMask {
+        self.intersection(other)
+    }
+}
+
+impl TryFrom<[u8; 6]> for TicketMask {
+    type Error = LotteryError;
+
+    fn try_from(ticket: [u8; 6]) -> Result<TicketMask, LotteryError> {
+        TicketMask::checked(&ticket)
+    }
+}
+
+impl TryFrom<&[u8; 6]> for TicketMask {
+    type Error = LotteryError;
+
+    fn try_from(ticket: &[u8; 6]) -> Result<TicketMask, LotteryError> {
+        TicketMask::checked(ticket)
+    }
+}
+
+impl LotteryDrawing {
+    /// 1등 번호 마스크
+    pub fn mask(&self) -> TicketMask {
+        TicketMask::from_numbers(&self.numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_rejects_numbers_the_mask_cannot_hold() {
+        assert_eq!(
+            TicketMask::try_from([1, 2, 3, 4, 5, 63]).map(TicketMask::to_ticket),
+            Ok(Some([1, 2, 3, 4, 5, 63]))
+        );
+        for number in [0, 64, 255] {
+            assert_eq!(
+                TicketMask::try_from([1, 2, 3, 4, 5, number]),
+                Err(LotteryError::NumberOutOfRange { number, min: 1, max: MAX_MASK_NUMBER })
+            );
+        }
+        assert_eq!(TicketMask::try_from(&[1, 2, 3, 4, 5, 5]), Err(LotteryError::DuplicateNumber(5)));
+    }
+
+    #[test]
+    fn check_ticket_validates_before_building_the_mask() {
+        let drawing = LotteryDrawing::new(1, [1, 2, 3, 4, 5, 6], 7).unwrap();
+
+        assert_eq!(crate::check_ticket(&[1, 2, 3, 4, 5, 7], &drawing).map(|check| check.tier), Ok(crate::PrizeTier::Second));
+        assert_eq!(
+            crate::check_ticket(&[0, 2, 3, 4, 5, 6], &drawing).map(|check| check.tier),
+            Err(LotteryError::NumberOutOfRange { number: 0, min: 1, max: MAX_MASK_NUMBER })
+        );
+        assert!(crate::check_ticket(&[1, 2, 3, 4, 5, 64], &drawing).is_err());
+    }
+}
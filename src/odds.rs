@@ -0,0 +1,199 @@
+//! 일치 개수 확률과 여러 장 티켓의 당첨 확률
+//!
+//! 본번호 k개 일치 확률은 초기하분포 C(pick, k) C(pool - pick, pick - k) / C(pool, pick)이고,
+//! 같은 추첨기 보너스는 티켓에서 맞지 않은 pick - k개 중 몇 개가 보너스로 나왔는지로 나눕니다.
+//! 여러 장의 확률은 티켓 번호 합집합 U에서 당첨번호와 겹치는 부분집합 X를 모두 나열하고
+//! 각 X에 C(pool - |U|, 6 - |X|)개의 추첨을 가중해 세므로, 티켓끼리 겹치는 번호가 정확히 반영됩니다.
+//! 당첨까지 걸리는 회차 수는 매 회차 같은 티켓을 산다고 보고 기하분포로 계산합니다.
+
+use serde::Serialize;
+
+use crate::game::for_each_combination;
+use crate::{binomial, tier_probabilities, BonusRule, GameSpec, LotteryError, PrizeTier, TicketMask};
+
+/// 1년 추첨 횟수 (주 1회)
+pub const DRAWS_PER_YEAR: f64 = 52.0;
+
+/// 티켓 1장이 본번호 matched개, 보너스 bonus_matched개를 맞출 확률
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchProbability {
+    pub matched: u8,
+    pub bonus_matched: u8,
+    pub probability: f64,
+    /// 1 / probability
+    pub one_in: f64,
+}
+
+/// 등수 이상 당첨 확률과 기다리는 회차 수
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TierOdds {
+    pub tier: PrizeTier,
+    /// 티켓 1장이 이 등수 이상일 확률
+    pub single: f64,
+    /// 티켓들이 서로 독립이라고 볼 때 1 - (1 - single)^N
+    pub independent: f64,
+    /// 한 장 이상이 이 등수 이상일 확률 (겹침 반영)
+    pub probability: f64,
+    pub one_in: f64,
+    /// 첫 당첨까지 평균 회차 수
+    pub expected_draws: f64,
+    /// 절반 확률로 당첨되는 회차 수
+    pub median_draws: f64,
+    /// expected_draws를 연 단위로 (주 1회 추첨)
+    pub expected_years: f64,
+}
+
+/// 티켓 묶음의 당첨 확률 계산 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OddsReport {
+    pub tickets: usize,
+    /// 티켓들에 쓰인 서로 다른 번호 수
+    pub distinct_numbers: usize,
+    /// 티켓 1장의 일치 개수별 확률 (본번호, 보너스 순)
+    pub matches: Vec<MatchProbability>,
+    /// 1-5등
+    pub tiers: Vec<TierOdds>,
+}
+
+impl OddsReport {
+    /// 등수 하나의 결과 (낙첨은 None)
+    pub fn tier(&self, tier: PrizeTier) -> Option<&TierOdds> {
+        self.tiers.iter().find(|odds| odds.tier == tier)
+    }
+}
+
+/// 티켓 1장의 본번호/보너스 일치 개수별 확률
+pub fn match_probabilities(game: &GameSpec) -> Vec<MatchProbability> {
+    let pool = game.pool as u64;
+    let pick = game.pick as u64;
+    let total = binomial(pool, pick) as f64;
+
+    let mut matches = Vec::new();
+    for matched in 0..=pick {
+        let main = (binomial(pick, matched) * binomial(pool - pick, pick - matched)) as f64 / total;
+
+        // (보너스 일치 개수, 조건부 확률)
+        let bonus: Vec<(u64, f64)> = match game.bonus {
+            BonusRule::None => vec![(0, 1.0)],
+            BonusRule::SameDrum { count } => {
+                // 보너스는 당첨번호가 아닌 pool - pick개 중에서 뽑히고, 티켓의 나머지 pick - matched개가 후보
+                let count = count as u64;
+                let missed = pick - matched;
+                let total = binomial(pool - pick, count) as f64;
+                (0..=count.min(missed))
+                    .map(|j| (j, (binomial(missed, j) * binomial(pool - pick - missed, count - j)) as f64 / total))
+                    .collect()
+            }
+            BonusRule::SeparateDrum { pool: bonus_pool, count } => {
+                let (bonus_pool, count) = (bonus_pool as u64, count as u64);
+                let total = binomial(bonus_pool, count) as f64;
+                (0..=count)
+                    .map(|j| (j, (binomial(count, j) * binomial(bonus_pool - count, count - j)) as f64 / total))
+                    .collect()
+            }
+        };
+
+        for (bonus_matched, conditional) in bonus {
+            let probability = main * conditional;
+            if probability > 0.0 {
+                matches.push(MatchProbability {
+                    matched: matched as u8,
+                    bonus_matched: bonus_matched as u8,
+                    probability,
+                    one_in: 1.0 / probability,
+                });
+            }
+        }
+    }
+
+    matches
+}
+
+/// 티켓 묶음을 한 회차에 함께 샀을 때 등수별 당첨 확률 (6개 선택 + 같은 추첨기 보너스 1개 게임)
+pub fn ticket_odds(game: &GameSpec, tickets: &[[u8; 6]]) -> Result<OddsReport, LotteryError> {
+    let single = tier_probabilities(game)?;
+    if tickets.is_empty() {
+        return Err(LotteryError::InvalidInput("확률을 계산할 티켓이 없습니다.".to_string()));
+    }
+    for ticket in tickets {
+        game.check_numbers(ticket)?;
+    }
+
+    let masks: Vec<TicketMask> = tickets.iter().map(|ticket| TicketMask::from_numbers(ticket)).collect();
+    let union = masks.iter().fold(TicketMask::EMPTY, |union, &mask| union | mask);
+    let numbers: Vec<u8> = union.iter().collect();
+    let outside = game.pool as u64 - numbers.len() as u64;
+    // 보너스 후보 = 당첨번호가 아닌 번호
+    let bonus_choices = (game.pool - 6) as f64;
+
+    // 1-5등 이상 당첨되는 추첨 수 (2등은 보너스 확률로 가중)
+    let mut hits = [0.0f64; 5];
+    for size in 0..=6usize.min(numbers.len()) {
+        let weight = binomial(outside, 6 - size as u64) as f64;
+        if weight == 0.0 {
+            continue;
+        }
+
+        for_each_combination(&numbers, size, |drawn| {
+            let drawn = TicketMask::from_numbers(drawn);
+            let mut best = 0;
+            // 5개 일치 티켓들의 빠진 번호 (이 중 하나가 보너스면 2등)
+            let mut missing = TicketMask::EMPTY;
+            for &mask in &masks {
+                let matched = mask.matches(drawn);
+                best = best.max(matched);
+                if matched == 5 {
+                    missing = missing | TicketMask(mask.0 & !drawn.0);
+                }
+            }
+
+            let second = if best == 6 { 1.0 } else { missing.len() as f64 / bonus_choices };
+            let reached = |threshold: u32| if best >= threshold { weight } else { 0.0 };
+            hits[PrizeTier::First.index()] += reached(6);
+            hits[PrizeTier::Second.index()] += weight * second;
+            hits[PrizeTier::Third.index()] += reached(5);
+            hits[PrizeTier::Fourth.index()] += reached(4);
+            hits[PrizeTier::Fifth.index()] += reached(3);
+        });
+    }
+
+    let total = game.combinations() as f64;
+    let count = tickets.len() as i32;
+    let tiers = PrizeTier::ALL
+        .into_iter()
+        .filter(|tier| tier.is_winning())
+        .map(|tier| {
+            let single: f64 = single[..=tier.index()].iter().sum();
+            let probability = hits[tier.index()] / total;
+            let expected_draws = 1.0 / probability;
+            TierOdds {
+                tier,
+                single,
+                independent: 1.0 - (1.0 - single).powi(count),
+                probability,
+                one_in: 1.0 / probability,
+                expected_draws,
+                median_draws: median_draws(probability),
+                expected_years: expected_draws / DRAWS_PER_YEAR,
+            }
+        })
+        .collect();
+
+    Ok(OddsReport {
+        tickets: tickets.len(),
+        distinct_numbers: numbers.len(),
+        matches: match_probabilities(game),
+        tiers,
+    })
+}
+
+// 1 - (1 - p)^n >= 1/2가 되는 가장 작은 n
+fn median_draws(probability: f64) -> f64 {
+    if probability >= 1.0 {
+        return 1.0;
+    }
+    (0.5f64.ln() / (-probability).ln_1p()).ceil()
+}
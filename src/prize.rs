@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{LotteryDrawing, LotteryError, TicketMask};
+
+/// 당첨 등수
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PrizeTier {
+    /// 6개 일치
+    First,
+    /// 5개 + 보너스 일치
+    Second,
+    /// 5개 일치
+    Third,
+    /// 4개 일치
+    Fourth,
+    /// 3개 일치
+    Fifth,
+    /// 낙첨
+    None,
+}
+
+impl PrizeTier {
+    /// 1등부터 낙첨까지 순서대로
+    pub const ALL: [PrizeTier; 6] = [
+        PrizeTier::First,
+        PrizeTier::Second,
+        PrizeTier::Third,
+        PrizeTier::Fourth,
+        PrizeTier::Fifth,
+        PrizeTier::None,
+    ];
+
+    /// 일치 개수와 보너스 일치 여부로 등수 결정
+    pub fn from_matches(matched: u32, bonus_matched: bool) -> PrizeTier {
+        match (matched, bonus_matched) {
+            (6, _) => PrizeTier::First,
+            (5, true) => PrizeTier::Second,
+            (5, false) => PrizeTier::Third,
+            (4, _) => PrizeTier::Fourth,
+            (3, _) => PrizeTier::Fifth,
+            _ => PrizeTier::None,
+        }
+    }
+
+    /// 등수 숫자 (낙첨은 None)
+    pub fn rank(self) -> Option<u8> {
+        match self {
+            PrizeTier::First => Some(1),
+            PrizeTier::Second => Some(2),
+            PrizeTier::Third => Some(3),
+            PrizeTier::Fourth => Some(4),
+            PrizeTier::Fifth => Some(5),
+            PrizeTier::None => None,
+        }
+    }
+
+    /// 등수 배열 인덱스 (1등 = 0, 낙첨 = 5)
+    pub fn index(self) -> usize {
+        self.rank().map_or(5, |rank| rank as usize - 1)
+    }
+
+    pub fn is_winning(self) -> bool {
+        self != PrizeTier::None
+    }
+}
+
+impl fmt::Display for PrizeTier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.rank() {
+            Some(rank) => write!(f, "{}등", rank),
+            None => write!(f, "낙첨"),
+        }
+    }
+}
+
+/// 등수별 당첨금 (원, 1-3등은 회차마다 달라지므로 평균값 사용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrizeTable {
+    pub first: u64,
+    pub second: u64,
+    pub third: u64,
+    pub fourth: u64,
+    pub fifth: u64,
+}
+
+impl Default for PrizeTable {
+    fn default() -> PrizeTable {
+        PrizeTable {
+            first: 2_000_000_000,
+            second: 55_000_000,
+            third: 1_500_000,
+            fourth: 50_000,
+            fifth: 5_000,
+        }
+    }
+}
+
+impl PrizeTable {
+    /// 등수별 당첨금 (낙첨은 0)
+    pub fn amount(&self, tier: PrizeTier) -> u64 {
+        match tier {
+            PrizeTier::First => self.first,
+            PrizeTier::Second => self.second,
+            PrizeTier::Third => self.third,
+            PrizeTier::Fourth => self.fourth,
+            PrizeTier::Fifth => self.fifth,
+            PrizeTier::None => 0,
+        }
+    }
+}
+
+/// 티켓 1장 가격 (원)
+pub const TICKET_PRICE: u64 = 1_000;
+
+/// 티켓 한 장의 당첨 확인 결과
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TicketCheck {
+    pub numbers: [u8; 6],
+    pub matched: u8,
+    pub bonus_matched: bool,
+    pub tier: PrizeTier,
+}
+
+/// 티켓을 회차 결과와 비교 (1-63 범위를 벗어나거나 중복된 번호는 에러)
+pub fn check_ticket(ticket: &[u8; 6], drawing: &LotteryDrawing) -> Result<TicketCheck, LotteryError> {
+    let ticket_mask = TicketMask::try_from(ticket)?;
+    let matched = ticket_mask.matches(drawing.mask());
+    let bonus_matched = ticket_mask.contains_bonus(drawing.bonus);
+
+    let mut numbers = *ticket;
+    numbers.sort();
+
+    Ok(TicketCheck {
+        numbers,
+        matched: matched as u8,
+        bonus_matched,
+        tier: PrizeTier::from_matches(matched, bonus_matched),
+    })
+}
@@ -0,0 +1,406 @@
+//! 추첨 기록 무작위성 검정
+//!
+//! 모두 "각 회차는 본번호 pick개를 1..=pool에서 균등하게, 회차끼리 독립으로 뽑는다"는 가설의 검정이며 본번호만 사용합니다.
+//! - 빈도 카이제곱: 한 회차의 번호는 서로 다르므로 Σ(O - E)²/E에 (pool - 1)/(pool - pick)을 곱하면 자유도 pool - 1의 카이제곱 분포를 따릅니다.
+//! - 런 검정: 홀수가 더 많은 회차(1)와 짝수가 더 많은 회차(0)의 순서(같은 회차는 제외)에 대한 Wald–Wolfowitz 검정입니다.
+//! - 계열 상관: 연속한 회차 합계의 1차 자기상관 r은 근사적으로 N(-1/n, 1/n)입니다.
+//! - 갭 검정: 번호별 연속 출현 사이의 갭 g는 p = pick/pool인 기하분포 p(1 - p)^g를 따르며, 기대 횟수 5 이상이 되도록 구간을 묶어 카이제곱으로 비교합니다.
+//! - 합계 KS: 회차 합계의 경험 분포를 조합 수로 계산한 정확한 합계 분포와 비교합니다(이산 분포이므로 p값은 보수적).
+
+use serde::Serialize;
+
+use crate::{DrawResult, GameSpec, LotteryError, RoundWindow};
+
+// 갭 검정 구간의 최소 기대 횟수
+const MIN_EXPECTED: f64 = 5.0;
+
+/// 번호 빈도 카이제곱 적합도 검정
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChiSquareTest {
+    /// 번호 하나의 기대 출현 횟수
+    pub expected: f64,
+    pub min_count: usize,
+    pub max_count: usize,
+    /// 보정한 카이제곱 통계량
+    pub statistic: f64,
+    pub degrees_of_freedom: usize,
+    pub p_value: f64,
+}
+
+/// 홀짝 런 검정
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunsTest {
+    /// 홀수가 더 많은 회차 수
+    pub odd_heavy: usize,
+    /// 짝수가 더 많은 회차 수
+    pub even_heavy: usize,
+    /// 홀짝 개수가 같아 제외한 회차 수
+    pub ties: usize,
+    pub runs: usize,
+    pub expected_runs: f64,
+    pub z_score: f64,
+    pub p_value: f64,
+}
+
+/// 연속 회차 합계의 계열 상관 검정
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerialCorrelationTest {
+    /// 1차 자기상관 계수
+    pub correlation: f64,
+    pub z_score: f64,
+    pub p_value: f64,
+}
+
+/// 갭 검정의 구간 하나 (to가 None이면 from 이상)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GapBin {
+    pub from: usize,
+    pub to: Option<usize>,
+    pub observed: usize,
+    pub expected: f64,
+}
+
+/// 번호별 출현 갭 검정
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GapTest {
+    /// 모든 번호의 갭 개수
+    pub gaps: usize,
+    pub bins: Vec<GapBin>,
+    pub statistic: f64,
+    pub degrees_of_freedom: usize,
+    pub p_value: f64,
+}
+
+/// 회차 합계 분포의 Kolmogorov–Smirnov 검정
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SumKsTest {
+    pub observed_mean: f64,
+    pub expected_mean: f64,
+    /// 경험 누적분포와 이론 누적분포의 최대 차이
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+/// 무작위성 검정 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RandomnessReport {
+    pub window: RoundWindow,
+    pub from_round: u32,
+    pub to_round: u32,
+    pub draws: usize,
+    pub frequency: ChiSquareTest,
+    pub runs: RunsTest,
+    pub serial: SerialCorrelationTest,
+    pub gap: GapTest,
+    pub sum: SumKsTest,
+}
+
+impl RandomnessReport {
+    /// 검정 이름과 p값 (보고 순서)
+    pub fn p_values(&self) -> [(&'static str, f64); 5] {
+        [
+            ("빈도 카이제곱", self.frequency.p_value),
+            ("홀짝 런", self.runs.p_value),
+            ("합계 계열 상관", self.serial.p_value),
+            ("갭", self.gap.p_value),
+            ("합계 KS", self.sum.p_value),
+        ]
+    }
+}
+
+impl GameSpec {
+    /// 구간 안 회차들에 대한 무작위성 검정 (2회차 이상 필요)
+    pub fn randomness_report<D: DrawResult>(&self, draws: &[D], window: RoundWindow) -> Result<RandomnessReport, LotteryError> {
+        let selected = window.select(draws)?;
+        if selected.len() < 2 {
+            return Err(LotteryError::InvalidInput(format!("{}: 검정에는 2회차 이상이 필요합니다.", window)));
+        }
+
+        let numbers: Vec<Vec<u8>> = selected
+            .iter()
+            .map(|draw| draw.main_numbers().iter().copied().filter(|num| self.main_range().contains(num)).collect())
+            .collect();
+
+        Ok(RandomnessReport {
+            window,
+            from_round: selected[0].round(),
+            to_round: selected[selected.len() - 1].round(),
+            draws: selected.len(),
+            frequency: self.frequency_test(&numbers),
+            runs: runs_test(&numbers),
+            serial: serial_test(&numbers),
+            gap: self.gap_test(&numbers),
+            sum: self.sum_test(&numbers),
+        })
+    }
+
+    fn frequency_test(&self, draws: &[Vec<u8>]) -> ChiSquareTest {
+        let mut counts = vec![0usize; self.pool as usize + 1];
+        for numbers in draws {
+            for &num in numbers {
+                counts[num as usize] += 1;
+            }
+        }
+        let counts = &counts[1..];
+
+        let pool = self.pool as f64;
+        let expected = draws.len() as f64 * self.pick as f64 / pool;
+        let raw: f64 = counts.iter().map(|&count| (count as f64 - expected).powi(2) / expected).sum();
+        let statistic = raw * (pool - 1.0) / (pool - self.pick as f64);
+        let degrees_of_freedom = self.pool as usize - 1;
+
+        ChiSquareTest {
+            expected,
+            min_count: counts.iter().copied().min().unwrap_or(0),
+            max_count: counts.iter().copied().max().unwrap_or(0),
+            statistic,
+            degrees_of_freedom,
+            p_value: chi_square_p(statistic, degrees_of_freedom),
+        }
+    }
+
+    fn gap_test(&self, draws: &[Vec<u8>]) -> GapTest {
+        let mut last_seen = vec![None; self.pool as usize + 1];
+        let mut gaps = Vec::new();
+        for (index, numbers) in draws.iter().enumerate() {
+            for &num in numbers {
+                if let Some(last) = last_seen[num as usize].replace(index) {
+                    gaps.push(index - last - 1);
+                }
+            }
+        }
+
+        // 갭 0..tail은 하나씩, tail 이상은 한 구간 (모든 구간 기대 횟수 MIN_EXPECTED 이상)
+        let p = self.pick as f64 / self.pool as f64;
+        let total = gaps.len() as f64;
+        let mut tail = 0;
+        while total * p * (1.0 - p).powi(tail as i32) >= MIN_EXPECTED && total * (1.0 - p).powi(tail as i32 + 1) >= MIN_EXPECTED {
+            tail += 1;
+        }
+
+        let mut bins: Vec<GapBin> = (0..tail)
+            .map(|gap| GapBin {
+                from: gap,
+                to: Some(gap),
+                observed: 0,
+                expected: total * p * (1.0 - p).powi(gap as i32),
+            })
+            .collect();
+        bins.push(GapBin {
+            from: tail,
+            to: None,
+            observed: 0,
+            expected: total * (1.0 - p).powi(tail as i32),
+        });
+        for &gap in &gaps {
+            bins[gap.min(tail)].observed += 1;
+        }
+
+        let statistic: f64 = bins
+            .iter()
+            .filter(|bin| bin.expected > 0.0)
+            .map(|bin| (bin.observed as f64 - bin.expected).powi(2) / bin.expected)
+            .sum();
+        let degrees_of_freedom = bins.len() - 1;
+
+        GapTest {
+            gaps: gaps.len(),
+            bins,
+            statistic,
+            degrees_of_freedom,
+            p_value: chi_square_p(statistic, degrees_of_freedom),
+        }
+    }
+
+    fn sum_test(&self, draws: &[Vec<u8>]) -> SumKsTest {
+        let distribution = self.sum_counts();
+        let total: f64 = distribution.iter().map(|&count| count as f64).sum();
+        let expected_mean = (self.pool as f64 + 1.0) * self.pick as f64 / 2.0;
+
+        let max_sum = distribution.len() - 1;
+        let mut observed = vec![0usize; distribution.len()];
+        for numbers in draws {
+            let sum: usize = numbers.iter().map(|&num| num as usize).sum();
+            observed[sum.min(max_sum)] += 1;
+        }
+
+        let n = draws.len() as f64;
+        let (mut theoretical_cdf, mut empirical_cdf, mut statistic) = (0.0, 0.0, 0.0f64);
+        for (count, seen) in distribution.iter().zip(&observed) {
+            theoretical_cdf += *count as f64 / total;
+            empirical_cdf += *seen as f64 / n;
+            statistic = statistic.max((empirical_cdf - theoretical_cdf).abs());
+        }
+
+        let observed_mean = draws.iter().map(|numbers| numbers.iter().map(|&num| num as f64).sum::<f64>()).sum::<f64>() / n;
+        let sqrt_n = n.sqrt();
+
+        SumKsTest {
+            observed_mean,
+            expected_mean,
+            statistic,
+            p_value: kolmogorov_p((sqrt_n + 0.12 + 0.11 / sqrt_n) * statistic),
+        }
+    }
+}
+
+fn runs_test(draws: &[Vec<u8>]) -> RunsTest {
+    let mut sequence = Vec::new();
+    let mut ties = 0;
+    for numbers in draws {
+        let odd = numbers.iter().filter(|&&num| num % 2 == 1).count();
+        let even = numbers.len() - odd;
+        match odd.cmp(&even) {
+            std::cmp::Ordering::Greater => sequence.push(true),
+            std::cmp::Ordering::Less => sequence.push(false),
+            std::cmp::Ordering::Equal => ties += 1,
+        }
+    }
+
+    let odd_heavy = sequence.iter().filter(|&&odd| odd).count();
+    let even_heavy = sequence.len() - odd_heavy;
+    let runs = if sequence.is_empty() { 0 } else { 1 + sequence.windows(2).filter(|pair| pair[0] != pair[1]).count() };
+
+    let (n1, n2) = (odd_heavy as f64, even_heavy as f64);
+    let n = n1 + n2;
+    let expected_runs = if n > 0.0 { 2.0 * n1 * n2 / n + 1.0 } else { 0.0 };
+    let variance = if n > 1.0 { 2.0 * n1 * n2 * (2.0 * n1 * n2 - n) / (n * n * (n - 1.0)) } else { 0.0 };
+    let z_score = if variance > 0.0 { (runs as f64 - expected_runs) / variance.sqrt() } else { 0.0 };
+
+    RunsTest {
+        odd_heavy,
+        even_heavy,
+        ties,
+        runs,
+        expected_runs,
+        z_score,
+        p_value: normal_two_sided_p(z_score),
+    }
+}
+
+fn serial_test(draws: &[Vec<u8>]) -> SerialCorrelationTest {
+    let sums: Vec<f64> = draws.iter().map(|numbers| numbers.iter().map(|&num| num as f64).sum()).collect();
+    let n = sums.len() as f64;
+    let mean = sums.iter().sum::<f64>() / n;
+
+    let variance: f64 = sums.iter().map(|sum| (sum - mean).powi(2)).sum();
+    let covariance: f64 = sums.windows(2).map(|pair| (pair[0] - mean) * (pair[1] - mean)).sum();
+    let correlation = if variance > 0.0 { covariance / variance } else { 0.0 };
+    let z_score = (correlation + 1.0 / n) * n.sqrt();
+
+    SerialCorrelationTest {
+        correlation,
+        z_score,
+        p_value: normal_two_sided_p(z_score),
+    }
+}
+
+// 카이제곱 상단 꼬리 확률 (자유도 0이면 1)
+fn chi_square_p(statistic: f64, degrees_of_freedom: usize) -> f64 {
+    if degrees_of_freedom == 0 {
+        return 1.0;
+    }
+    upper_gamma_q(degrees_of_freedom as f64 / 2.0, statistic / 2.0)
+}
+
+// 표준정규 양측 p값 = erfc(|z|/√2) = Q(1/2, z²/2)
+fn normal_two_sided_p(z: f64) -> f64 {
+    upper_gamma_q(0.5, z * z / 2.0)
+}
+
+// Kolmogorov 분포 상단 꼬리 2Σ(-1)^(j-1) exp(-2j²λ²)
+fn kolmogorov_p(lambda: f64) -> f64 {
+    if lambda < 0.3 {
+        return 1.0;
+    }
+
+    let mut sum = 0.0;
+    let mut sign = 1.0;
+    for j in 1..=100 {
+        let term = sign * (-2.0 * (j * j) as f64 * lambda * lambda).exp();
+        sum += term;
+        if term.abs() < 1e-12 {
+            break;
+        }
+        sign = -sign;
+    }
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
+// 정규화 상단 불완전 감마 함수 Q(a, x) (x < a + 1이면 급수, 아니면 연분수)
+fn upper_gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let ln_prefix = a * x.ln() - x - ln_gamma(a);
+
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        let mut ap = a;
+        for _ in 0..1000 {
+            ap += 1.0;
+            term *= x / ap;
+            sum += term;
+            if term.abs() < sum.abs() * 1e-15 {
+                break;
+            }
+        }
+        (1.0 - sum * ln_prefix.exp()).clamp(0.0, 1.0)
+    } else {
+        // 수정 Lentz 방법
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..1000 {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < 1e-15 {
+                break;
+            }
+        }
+        (ln_prefix.exp() * h).clamp(0.0, 1.0)
+    }
+}
+
+// Lanczos 근사 ln Γ(x) (x > 0)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.001_208_650_973_866_179,
+        -0.000_005_395_239_384_953,
+    ];
+
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let mut series = 1.000_000_000_190_015;
+    let mut y = x;
+    for coefficient in COEFFICIENTS {
+        y += 1.0;
+        series += coefficient / y;
+    }
+    -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
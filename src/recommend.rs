@@ -0,0 +1,291 @@
+//! 점수 기반 번호 추천
+//!
+//! 번호 점수 = 전체 빈도, 최근 N회 빈도, 마지막 출현 후 경과 회차(갭)를 각각 0-1로 정규화한 가중합.
+//! 조합 점수 = 번호 점수 평균 × number_weight + (쌍 동시출현 평균 / 최대 쌍 동시출현) × pair_weight.
+//! 후보는 (번호 점수 + 0.05)^temperature 가중치로 뽑으며, temperature 0이면 균등 추출입니다.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::{
+    diverse_select, DiverseSelection, DiversityConfig, Exclusion, Filter, GameSpec, LotteryDrawing, LotteryError,
+    PairCounts,
+};
+
+/// 추천 점수/샘플링 설정
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RecommenderConfig {
+    /// 전체 빈도 가중치
+    pub frequency_weight: f64,
+    /// 최근 빈도 가중치
+    pub recent_weight: f64,
+    /// 미출현 갭 가중치 (갭이 클수록 높은 점수)
+    pub gap_weight: f64,
+    /// 최근 빈도를 셀 회차 수
+    pub recent_window: usize,
+    /// 샘플링 온도 (0 = 균등, 1 = 점수 그대로, 1 미만이면 평탄화)
+    pub temperature: f64,
+    /// 조합 점수 중 번호 점수 비중
+    pub number_weight: f64,
+    /// 조합 점수 중 쌍 동시출현 비중
+    pub pair_weight: f64,
+    /// 후보 샘플링 최대 시도 횟수
+    pub attempts: usize,
+    /// 모을 후보 최대 개수
+    pub max_candidates: usize,
+}
+
+impl Default for RecommenderConfig {
+    fn default() -> RecommenderConfig {
+        RecommenderConfig {
+            frequency_weight: 0.25,
+            recent_weight: 0.40,
+            gap_weight: 0.35,
+            recent_window: 50,
+            temperature: 0.5,
+            number_weight: 0.6,
+            pair_weight: 0.4,
+            attempts: 300_000,
+            max_candidates: 8_000,
+        }
+    }
+}
+
+/// 번호별 점수 구성
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NumberScore {
+    pub number: u8,
+    /// 전체 출현 횟수
+    pub frequency: usize,
+    /// 최근 recent_window회 출현 횟수
+    pub recent: usize,
+    /// 마지막 출현 후 지난 회차 수 (한 번도 안 나왔으면 전체 회차 수)
+    pub gap: usize,
+    /// 정규화 가중합 (0-1)
+    pub score: f64,
+}
+
+/// 점수가 매겨진 티켓
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoredTicket {
+    pub numbers: [u8; 6],
+    pub score: f64,
+}
+
+/// 과거 회차 통계로 번호와 조합에 점수를 매기는 추천기
+#[derive(Debug, Clone)]
+pub struct Recommender {
+    config: RecommenderConfig,
+    pool: u8,
+    numbers: Vec<NumberScore>,
+    pair_counts: PairCounts,
+    max_pair_count: u32,
+}
+
+impl Recommender {
+    /// 회차순 데이터로 통계 계산 (1등 번호만 사용)
+    pub fn new(game: &GameSpec, drawings: &[LotteryDrawing], config: RecommenderConfig) -> Result<Recommender, LotteryError> {
+        let weights = [
+            config.frequency_weight,
+            config.recent_weight,
+            config.gap_weight,
+            config.temperature,
+            config.number_weight,
+            config.pair_weight,
+        ];
+        if weights.iter().any(|w| !(w.is_finite() && *w >= 0.0)) {
+            return Err(LotteryError::InvalidInput("추천 가중치와 온도는 0 이상이어야 합니다.".to_string()));
+        }
+
+        let size = game.pool as usize + 1;
+        let mut frequency = vec![0usize; size];
+        let mut recent = vec![0usize; size];
+        let mut last_seen = vec![None; size];
+        let recent_from = drawings.len().saturating_sub(config.recent_window);
+
+        for (index, drawing) in drawings.iter().enumerate() {
+            let numbers = drawing.sorted_numbers();
+            for &num in &numbers {
+                frequency[num as usize] += 1;
+                last_seen[num as usize] = Some(index);
+                if index >= recent_from {
+                    recent[num as usize] += 1;
+                }
+            }
+        }
+
+        let gap: Vec<usize> = last_seen
+            .iter()
+            .map(|seen| seen.map_or(drawings.len(), |index| drawings.len() - 1 - index))
+            .collect();
+
+        let range = game.main_range();
+        let frequency_norm = normalize(&frequency, range.clone());
+        let recent_norm = normalize(&recent, range.clone());
+        let gap_norm = normalize(&gap, range.clone());
+
+        let numbers = range
+            .map(|num| {
+                let n = num as usize;
+                NumberScore {
+                    number: num,
+                    frequency: frequency[n],
+                    recent: recent[n],
+                    gap: gap[n],
+                    score: config.frequency_weight * frequency_norm[n]
+                        + config.recent_weight * recent_norm[n]
+                        + config.gap_weight * gap_norm[n],
+                }
+            })
+            .collect();
+
+        let pair_counts = PairCounts::new(game, drawings);
+        let max_pair_count = pair_counts.max().max(1);
+
+        Ok(Recommender {
+            config,
+            pool: game.pool,
+            numbers,
+            pair_counts,
+            max_pair_count,
+        })
+    }
+
+    pub fn config(&self) -> &RecommenderConfig {
+        &self.config
+    }
+
+    /// 번호별 점수 (번호순)
+    pub fn number_scores(&self) -> &[NumberScore] {
+        &self.numbers
+    }
+
+    /// 번호 점수 (범위 밖이면 0)
+    pub fn number_score(&self, num: u8) -> f64 {
+        if (1..=self.pool).contains(&num) {
+            self.numbers[num as usize - 1].score
+        } else {
+            0.0
+        }
+    }
+
+    /// 조합 안 모든 쌍의 평균 동시출현 횟수
+    pub fn pair_score(&self, numbers: &[u8]) -> f64 {
+        let mut total = 0;
+        let mut pairs = 0;
+        for (i, &a) in numbers.iter().enumerate() {
+            for &b in &numbers[i + 1..] {
+                total += self.pair_counts.count(a, b);
+                pairs += 1;
+            }
+        }
+
+        if pairs == 0 {
+            0.0
+        } else {
+            total as f64 / pairs as f64
+        }
+    }
+
+    /// 조합 점수 (0-1 근사)
+    pub fn score(&self, numbers: &[u8]) -> f64 {
+        let number_score = numbers.iter().map(|&n| self.number_score(n)).sum::<f64>() / numbers.len() as f64;
+        let pair_score = self.pair_score(numbers) / self.max_pair_count as f64;
+        number_score * self.config.number_weight + pair_score * self.config.pair_weight
+    }
+
+    /// 온도 가중치로 정렬된 6개 번호 추출
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [u8; 6] {
+        let temperature = self.config.temperature;
+        let mut ticket = [0u8; 6];
+
+        let chosen = self
+            .numbers
+            .choose_multiple_weighted(rng, 6, |number| (number.score + 0.05).powf(temperature))
+            .expect("가중치는 항상 양수");
+        for (slot, number) in ticket.iter_mut().zip(chosen) {
+            *slot = number.number;
+        }
+
+        ticket.sort();
+        ticket
+    }
+
+    /// 제외 조합과 필터를 통과한 서로 다른 후보를 모아 점수 내림차순 정렬
+    pub fn candidates<R: Rng + ?Sized>(&self, exclusion: &Exclusion, filter: &dyn Filter, rng: &mut R) -> Vec<ScoredTicket> {
+        let mut seen = HashSet::new();
+        let mut candidates = Vec::new();
+
+        for _ in 0..self.config.attempts {
+            if candidates.len() >= self.config.max_candidates {
+                break;
+            }
+
+            let numbers = self.sample(rng);
+            if !seen.insert(numbers) || exclusion.excludes(&numbers) || !filter.accepts(&numbers) {
+                continue;
+            }
+
+            candidates.push(ScoredTicket { numbers, score: self.score(&numbers) });
+        }
+
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.numbers.cmp(&b.numbers)));
+        candidates
+    }
+
+    /// 점수 상위 count개 추천
+    pub fn recommend<R: Rng + ?Sized>(
+        &self,
+        exclusion: &Exclusion,
+        filter: &dyn Filter,
+        count: usize,
+        rng: &mut R,
+    ) -> Result<Vec<ScoredTicket>, LotteryError> {
+        let mut candidates = self.candidates(exclusion, filter, rng);
+        if candidates.len() < count {
+            return Err(LotteryError::InsufficientCombinations {
+                requested: count,
+                found: candidates.len(),
+            });
+        }
+
+        candidates.truncate(count);
+        Ok(candidates)
+    }
+
+    /// 점수 상위 후보에서 다양성 선택으로 count개 추천 (후보가 부족하면 더 적게 반환)
+    pub fn recommend_diverse<R: Rng + ?Sized>(
+        &self,
+        exclusion: &Exclusion,
+        filter: &dyn Filter,
+        count: usize,
+        diversity: &DiversityConfig,
+        rng: &mut R,
+    ) -> DiverseSelection {
+        let candidates = self.candidates(exclusion, filter, rng);
+        diverse_select(&candidates, count, diversity)
+    }
+}
+
+// 번호 범위 안의 값을 0-1로 정규화 (모두 같으면 0.5)
+fn normalize(values: &[usize], range: std::ops::RangeInclusive<u8>) -> Vec<f64> {
+    let in_range = || range.clone().map(|n| values[n as usize]);
+    let min = in_range().min().unwrap_or(0);
+    let max = in_range().max().unwrap_or(0);
+
+    values
+        .iter()
+        .map(|&value| {
+            if max == min {
+                0.5
+            } else {
+                (value as f64 - min as f64) / (max - min) as f64
+            }
+        })
+        .collect()
+}
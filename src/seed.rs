@@ -0,0 +1,18 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+/// 네이티브와 WASM에서 같은 시드로 같은 결과를 내는 난수 생성기
+pub type LotteryRng = ChaCha20Rng;
+
+/// 시드가 있으면 고정 스트림, 없으면 OS 엔트로피로 초기화
+pub fn seeded_rng(seed: Option<u64>) -> LotteryRng {
+    match seed {
+        Some(seed) => LotteryRng::seed_from_u64(seed),
+        None => LotteryRng::from_entropy(),
+    }
+}
+
+/// 사용자에게 보여줄 새 시드
+pub fn random_seed() -> u64 {
+    rand::random()
+}
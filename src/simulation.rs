@@ -0,0 +1,506 @@
+//! 장기 구매 몬테카를로 시뮬레이션
+//!
+//! 전략대로 매주 티켓을 사서 weeks주 동안 채점하는 것을 runs번 반복합니다.
+//! 추첨은 균등 추출(본번호 6개 + 보너스 1개) 또는 실제 회차 기록의 복원 추출 중에서 고르며,
+//! 채점은 check_ticket과 당첨금 표를 그대로 사용합니다.
+//! 반복마다 같은 시드의 다른 ChaCha 스트림(스트림 번호 = 반복 번호)을 쓰므로 스레드 수와 관계없이 결과가 같습니다.
+//! 파산 위험은 시작 예산에 당첨금을 더해 가며 다음 주 구매 비용을 낼 수 없게 되는 비율이고,
+//! 순손익 분포는 파산 여부와 관계없이 weeks주를 모두 구매했다고 보고 계산합니다.
+
+use rand::seq::index::sample;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::thread;
+
+use crate::game::TicketSampler;
+use crate::{
+    check_ticket, random_seed, tier_probabilities, Exclusion, GameSpec, LotteryDrawing, LotteryError, LotteryRng,
+    NumberConstraints, PrizeTable, PrizeTier, TICKET_PRICE,
+};
+
+/// 매주 티켓을 고르는 방법
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PlayStrategy {
+    /// 매주 같은 티켓
+    Fixed { tickets: Vec<[u8; 6]> },
+    /// 매주 새 자동 번호 count장 (generate_sets와 같은 제외 조합)
+    QuickPick { count: usize },
+    /// 포함/제외 번호, 번호 풀 조건을 만족하는 반자동 번호 count장
+    Constrained { constraints: NumberConstraints, count: usize },
+}
+
+impl PlayStrategy {
+    /// 주당 티켓 수
+    pub fn tickets_per_week(&self) -> usize {
+        match self {
+            PlayStrategy::Fixed { tickets } => tickets.len(),
+            PlayStrategy::QuickPick { count } | PlayStrategy::Constrained { count, .. } => *count,
+        }
+    }
+
+    /// 보고서에 표시할 이름
+    pub fn name(&self) -> String {
+        match self {
+            PlayStrategy::Fixed { tickets } => format!("고정 번호 {}장", tickets.len()),
+            PlayStrategy::QuickPick { count } => format!("자동 {}장", count),
+            PlayStrategy::Constrained { constraints, count } => {
+                let mut parts = Vec::new();
+                if !constraints.required.is_empty() {
+                    parts.push(format!("포함 {:?}", constraints.required));
+                }
+                if !constraints.excluded.is_empty() {
+                    parts.push(format!("제외 {:?}", constraints.excluded));
+                }
+                if let Some(pool) = &constraints.pool {
+                    parts.push(format!("번호 풀 {}개", pool.len()));
+                }
+                format!("반자동 {}장 ({})", count, parts.join(", "))
+            }
+        }
+    }
+}
+
+/// 시뮬레이션 추첨 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DrawSource {
+    /// 균등 추출
+    #[default]
+    Synthetic,
+    /// 실제 회차 기록에서 복원 추출
+    Resample,
+}
+
+/// 시뮬레이션 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationConfig {
+    pub strategy: PlayStrategy,
+    pub source: DrawSource,
+    /// 반복마다 구매하는 주 수
+    pub weeks: usize,
+    /// 반복 횟수
+    pub runs: usize,
+    /// 시작 예산 (원, 파산 위험 계산용)
+    pub budget: Option<u64>,
+    pub ticket_price: u64,
+    pub prizes: PrizeTable,
+    /// 없으면 새 시드를 뽑아 보고서에 기록
+    pub seed: Option<u64>,
+    /// 작업 스레드 수 (0이면 사용 가능한 코어 수)
+    pub threads: usize,
+}
+
+impl SimulationConfig {
+    /// 기본값: 10년(520주) × 10,000회, 1,000원, 평균 당첨금, 예산 없음
+    pub fn new(strategy: PlayStrategy, source: DrawSource) -> SimulationConfig {
+        SimulationConfig {
+            strategy,
+            source,
+            weeks: 520,
+            runs: 10_000,
+            budget: None,
+            ticket_price: TICKET_PRICE,
+            prizes: PrizeTable::default(),
+            seed: None,
+            threads: 0,
+        }
+    }
+}
+
+/// 반복별 순손익 분포
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetDistribution {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: i64,
+    pub max: i64,
+    /// (백분위수, 순손익) 최근접 순위
+    pub percentiles: Vec<(u8, i64)>,
+    /// 순손익이 0보다 큰 반복 비율
+    pub profitable: f64,
+    /// 전체 당첨금 / 전체 구매액
+    pub return_rate: f64,
+}
+
+/// 예산 기준 파산 위험
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuinStats {
+    pub budget: u64,
+    /// 다음 주 구매 비용을 낼 수 없게 된 반복 비율
+    pub probability: f64,
+    /// 파산한 반복의 파산 주 중앙값 (1부터)
+    pub median_week: Option<usize>,
+}
+
+/// 반복별 최장 연속 낙첨 주 수
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakStats {
+    pub mean: f64,
+    pub median: usize,
+    pub max: usize,
+}
+
+/// 등수 하나의 당첨 경험
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TierChance {
+    pub tier: PrizeTier,
+    /// 한 번 이상 당첨된 반복 비율
+    pub ever: f64,
+    /// 반복당 평균 당첨 횟수
+    pub mean_hits: f64,
+}
+
+/// 시뮬레이션 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationReport {
+    pub strategy: String,
+    pub source: DrawSource,
+    pub weeks: usize,
+    pub runs: usize,
+    pub tickets_per_week: usize,
+    pub seed: u64,
+    /// 반복 하나의 총 구매액
+    pub cost: u64,
+    pub net: NetDistribution,
+    pub ruin: Option<RuinStats>,
+    /// 당첨 없는 주가 이어진 최장 길이
+    pub losing_streak: StreakStats,
+    /// 1-5등
+    pub tiers: Vec<TierChance>,
+}
+
+impl SimulationReport {
+    /// 등수 하나의 결과 (낙첨은 None)
+    pub fn tier(&self, tier: PrizeTier) -> Option<&TierChance> {
+        self.tiers.iter().find(|chance| chance.tier == tier)
+    }
+}
+
+// 반복 하나의 결과
+struct RunOutcome {
+    net: i64,
+    winnings: u64,
+    ruined_at: Option<usize>,
+    longest_streak: usize,
+    hits: [u32; 5],
+}
+
+// 반복들이 함께 쓰는 읽기 전용 상태
+struct Simulator<'a> {
+    game: &'a GameSpec,
+    history: &'a [LotteryDrawing],
+    exclusion: &'a Exclusion,
+    config: &'a SimulationConfig,
+    seed: u64,
+}
+
+impl<'a> Simulator<'a> {
+    // 자동/반자동 전략의 티켓 추출기 (스레드마다 따로 만듦)
+    fn sampler(&self) -> Result<Option<TicketSampler<'a>>, LotteryError> {
+        let constraints = match &self.config.strategy {
+            PlayStrategy::Fixed { .. } => return Ok(None),
+            PlayStrategy::QuickPick { .. } => &NumberConstraints::default(),
+            PlayStrategy::Constrained { constraints, .. } => constraints,
+        };
+        TicketSampler::new(self.game, self.exclusion, None, constraints).map(Some)
+    }
+
+    fn run(&self, index: usize, sampler: &mut Option<TicketSampler>) -> Result<RunOutcome, LotteryError> {
+        let mut rng = LotteryRng::seed_from_u64(self.seed);
+        rng.set_stream(index as u64);
+
+        let weekly_cost = (self.config.strategy.tickets_per_week() as u64 * self.config.ticket_price) as i64;
+        let mut outcome = RunOutcome {
+            net: 0,
+            winnings: 0,
+            ruined_at: None,
+            longest_streak: 0,
+            hits: [0; 5],
+        };
+        let mut streak = 0;
+
+        for week in 0..self.config.weeks {
+            if let Some(budget) = self.config.budget {
+                if outcome.ruined_at.is_none() && budget as i64 + outcome.net < weekly_cost {
+                    outcome.ruined_at = Some(week + 1);
+                }
+            }
+
+            let drawing = self.draw(&mut rng);
+            let tickets = self.tickets(sampler, &mut rng)?;
+
+            let mut won = 0;
+            for ticket in tickets.iter() {
+                let tier = check_ticket(ticket, &drawing)?.tier;
+                if tier.is_winning() {
+                    outcome.hits[tier.index()] += 1;
+                    won += self.config.prizes.amount(tier);
+                }
+            }
+
+            if won == 0 {
+                streak += 1;
+                outcome.longest_streak = outcome.longest_streak.max(streak);
+            } else {
+                streak = 0;
+            }
+            outcome.winnings += won;
+            outcome.net += won as i64 - weekly_cost;
+        }
+
+        Ok(outcome)
+    }
+
+    fn draw(&self, rng: &mut LotteryRng) -> LotteryDrawing {
+        match self.config.source {
+            DrawSource::Synthetic => {
+                let picked = sample(rng, self.game.pool as usize, 7);
+                let mut numbers = [0u8; 6];
+                for (slot, index) in numbers.iter_mut().zip(picked.iter()) {
+                    *slot = index as u8 + 1;
+                }
+                LotteryDrawing {
+                    round: 0,
+                    numbers,
+                    bonus: picked.index(6) as u8 + 1,
+                }
+            }
+            DrawSource::Resample => self.history[rng.gen_range(0..self.history.len())].clone(),
+        }
+    }
+
+    fn tickets(&self, sampler: &mut Option<TicketSampler>, rng: &mut LotteryRng) -> Result<Cow<'_, [[u8; 6]]>, LotteryError> {
+        if let PlayStrategy::Fixed { tickets } = &self.config.strategy {
+            return Ok(tickets.as_slice().into());
+        }
+
+        let sampler = sampler.as_mut().expect("자동/반자동 전략은 추출기가 있음");
+        let tickets = sampler.sample(self.config.strategy.tickets_per_week(), rng)?;
+        Ok(tickets
+            .into_iter()
+            .map(|ticket| ticket.numbers.try_into().expect("6개 선택 게임만 허용"))
+            .collect::<Vec<[u8; 6]>>()
+            .into())
+    }
+}
+
+/// 전략을 weeks주 동안 runs번 반복해 순손익 분포, 파산 위험, 최장 연속 낙첨, 등수별 당첨 경험을 집계
+///
+/// history는 복원 추출할 회차 기록, exclusion은 자동/반자동 번호에서 뺄 조합입니다.
+/// 6개 선택 + 같은 추첨기 보너스 1개 게임만 지원하며, 다른 게임은 UnsupportedGame 오류입니다.
+pub fn simulate(
+    game: &GameSpec,
+    history: &[LotteryDrawing],
+    exclusion: &Exclusion,
+    config: &SimulationConfig,
+) -> Result<SimulationReport, LotteryError> {
+    // 추첨과 채점이 6개 선택 + 같은 추첨기 보너스 1개를 전제로 함
+    tier_probabilities(game)?;
+    if config.weeks == 0 || config.runs == 0 {
+        return Err(LotteryError::InvalidInput("주 수와 반복 횟수는 1 이상이어야 합니다.".to_string()));
+    }
+    if config.strategy.tickets_per_week() == 0 {
+        return Err(LotteryError::InvalidInput("주당 티켓 수는 1 이상이어야 합니다.".to_string()));
+    }
+    if config.source == DrawSource::Resample && history.is_empty() {
+        return Err(LotteryError::InvalidInput("복원 추출할 회차가 없습니다.".to_string()));
+    }
+    if let PlayStrategy::Fixed { tickets } = &config.strategy {
+        for ticket in tickets {
+            game.check_numbers(ticket)?;
+        }
+    }
+
+    let simulator = Simulator {
+        game,
+        history,
+        exclusion,
+        config,
+        seed: config.seed.unwrap_or_else(random_seed),
+    };
+
+    // 조건을 만족하는 조합이 모자라면 반복을 시작하기 전에 알림
+    if let Some(mut sampler) = simulator.sampler()? {
+        sampler.sample(config.strategy.tickets_per_week(), &mut LotteryRng::seed_from_u64(0))?;
+    }
+
+    let threads = match config.threads {
+        0 => thread::available_parallelism().map_or(1, |threads| threads.get()),
+        threads => threads,
+    }
+    .min(config.runs);
+    let chunk = config.runs.div_ceil(threads);
+
+    let outcomes: Vec<RunOutcome> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..config.runs)
+            .step_by(chunk)
+            .map(|start| {
+                let simulator = &simulator;
+                scope.spawn(move || {
+                    let mut sampler = simulator.sampler()?;
+                    (start..(start + chunk).min(config.runs))
+                        .map(|index| simulator.run(index, &mut sampler))
+                        .collect::<Result<Vec<RunOutcome>, LotteryError>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("시뮬레이션 스레드는 패닉하지 않음"))
+            .collect::<Result<Vec<Vec<RunOutcome>>, LotteryError>>()
+    })?
+    .into_iter()
+    .flatten()
+    .collect();
+
+    Ok(summarize(&simulator, &outcomes))
+}
+
+fn summarize(simulator: &Simulator, outcomes: &[RunOutcome]) -> SimulationReport {
+    let config = simulator.config;
+    let runs = outcomes.len() as f64;
+    let tickets_per_week = config.strategy.tickets_per_week();
+    let cost = (config.weeks * tickets_per_week) as u64 * config.ticket_price;
+
+    let mut nets: Vec<i64> = outcomes.iter().map(|outcome| outcome.net).collect();
+    nets.sort();
+    let mean = nets.iter().map(|&net| net as f64).sum::<f64>() / runs;
+    let variance = nets.iter().map(|&net| (net as f64 - mean).powi(2)).sum::<f64>() / runs;
+    let winnings: f64 = outcomes.iter().map(|outcome| outcome.winnings as f64).sum();
+
+    let net = NetDistribution {
+        mean,
+        std_dev: variance.sqrt(),
+        min: nets[0],
+        max: nets[nets.len() - 1],
+        percentiles: [5, 25, 50, 75, 95].into_iter().map(|level| (level, *percentile(&nets, level))).collect(),
+        profitable: nets.iter().filter(|&&net| net > 0).count() as f64 / runs,
+        return_rate: winnings / (cost as f64 * runs),
+    };
+
+    let ruin = config.budget.map(|budget| {
+        let mut weeks: Vec<usize> = outcomes.iter().filter_map(|outcome| outcome.ruined_at).collect();
+        weeks.sort();
+        RuinStats {
+            budget,
+            probability: weeks.len() as f64 / runs,
+            median_week: (!weeks.is_empty()).then(|| *percentile(&weeks, 50)),
+        }
+    });
+
+    let mut streaks: Vec<usize> = outcomes.iter().map(|outcome| outcome.longest_streak).collect();
+    streaks.sort();
+    let losing_streak = StreakStats {
+        mean: streaks.iter().sum::<usize>() as f64 / runs,
+        median: *percentile(&streaks, 50),
+        max: streaks[streaks.len() - 1],
+    };
+
+    let tiers = PrizeTier::ALL
+        .into_iter()
+        .filter(|tier| tier.is_winning())
+        .map(|tier| {
+            let hits = outcomes.iter().map(|outcome| outcome.hits[tier.index()]);
+            TierChance {
+                tier,
+                ever: hits.clone().filter(|&count| count > 0).count() as f64 / runs,
+                mean_hits: hits.map(|count| count as f64).sum::<f64>() / runs,
+            }
+        })
+        .collect();
+
+    SimulationReport {
+        strategy: config.strategy.name(),
+        source: config.source,
+        weeks: config.weeks,
+        runs: outcomes.len(),
+        tickets_per_week,
+        seed: simulator.seed,
+        cost,
+        net,
+        ruin,
+        losing_streak,
+        tiers,
+    }
+}
+
+// 정렬된 값의 level 백분위수 (최근접 순위)
+fn percentile<T>(sorted: &[T], level: u8) -> &T {
+    let rank = (level as f64 / 100.0 * sorted.len() as f64).ceil().max(1.0) as usize;
+    &sorted[rank.min(sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn games_other_than_pick_six_with_same_drum_bonus_are_rejected() {
+        let mut config = SimulationConfig::new(PlayStrategy::QuickPick { count: 1 }, DrawSource::Synthetic);
+        config.weeks = 1;
+        config.runs = 1;
+
+        for game in [GameSpec::powerball(), GameSpec::euromillions()] {
+            let result = simulate(&game, &[], &Exclusion::none(), &config);
+            assert_eq!(result.unwrap_err(), LotteryError::UnsupportedGame(game.name.clone()));
+        }
+        assert!(simulate(&GameSpec::lotto_649(), &[], &Exclusion::none(), &config).is_ok());
+    }
+
+    fn history() -> Vec<LotteryDrawing> {
+        vec![
+            LotteryDrawing::new(1, [10, 23, 29, 33, 37, 40], 16).unwrap(),
+            LotteryDrawing::new(2, [9, 13, 21, 25, 32, 42], 2).unwrap(),
+            LotteryDrawing::new(3, [11, 16, 19, 21, 27, 31], 30).unwrap(),
+        ]
+    }
+
+    fn config(strategy: PlayStrategy, source: DrawSource, seed: u64, threads: usize) -> SimulationConfig {
+        let mut config = SimulationConfig::new(strategy, source);
+        config.weeks = 52;
+        config.runs = 30;
+        config.budget = Some(20_000);
+        config.seed = Some(seed);
+        config.threads = threads;
+        config
+    }
+
+    #[test]
+    fn same_seed_gives_same_report_for_any_thread_count() {
+        let game = GameSpec::lotto_645();
+        let history = history();
+        let exclusion = game.exclusion(&crate::ExclusionPolicy::SecondPlace, &history).unwrap();
+        let constraints = NumberConstraints { required: vec![7], excluded: vec![13], pool: None };
+        let cases = [
+            (PlayStrategy::QuickPick { count: 5 }, DrawSource::Synthetic),
+            (PlayStrategy::Constrained { constraints, count: 3 }, DrawSource::Resample),
+            (PlayStrategy::Fixed { tickets: vec![[1, 2, 3, 4, 5, 6], [7, 13, 21, 25, 32, 42]] }, DrawSource::Synthetic),
+        ];
+
+        for (strategy, source) in cases {
+            let single = simulate(&game, &history, &exclusion, &config(strategy.clone(), source, 5, 1)).unwrap();
+            for threads in [3, 4] {
+                let parallel = simulate(&game, &history, &exclusion, &config(strategy.clone(), source, 5, threads)).unwrap();
+                assert_eq!(single, parallel, "{} threads", threads);
+            }
+        }
+    }
+
+    #[test]
+    fn different_seeds_give_different_reports() {
+        let game = GameSpec::lotto_645();
+        let strategy = PlayStrategy::QuickPick { count: 5 };
+        let a = simulate(&game, &[], &Exclusion::none(), &config(strategy.clone(), DrawSource::Synthetic, 1, 1)).unwrap();
+        let b = simulate(&game, &[], &Exclusion::none(), &config(strategy, DrawSource::Synthetic, 2, 1)).unwrap();
+
+        assert_ne!((&a.net, &a.tiers), (&b.net, &b.tiers));
+    }
+}
@@ -0,0 +1,342 @@
+//! 휠링 (번호 풀 조합 구매)
+//!
+//! 키 번호는 모든 티켓에 들어가고, 나머지 자리(6 - 키 개수)는 풀에서 채웁니다.
+//! 보장 조건 "풀에서 if_drawn개가 나오면 matched개 일치"는 키 번호가 모두 나왔다는 전제에서
+//! 풀 번호끼리만 센 값이며, 실제 일치 개수는 여기에 키 개수를 더한 값입니다.
+//! 축약 휠은 아직 덮이지 않은 경우 하나를 골라, 그 경우를 덮으면서 가장 많은 경우를 덮는 티켓을
+//! 탐욕적으로 추가해 만듭니다(최소 티켓 수는 보장하지 않음).
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use crate::game::for_each_combination;
+use crate::{binomial, Exclusion, GameSpec, LotteryError, TicketMask, TICKET_PRICE};
+
+/// 휠 풀(키 번호 제외)의 최대 크기
+pub const MAX_WHEEL_POOL: usize = 20;
+
+/// 휠 보장 조건 (풀에서 if_drawn개가 나오면 어떤 티켓은 그중 matched개 이상 일치)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WheelGuarantee {
+    pub if_drawn: u8,
+    pub matched: u8,
+}
+
+/// 보장 조건 검증 결과
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuaranteeCheck {
+    /// 풀에서 if_drawn개가 나오는 경우의 수
+    pub cases: u64,
+    /// 그중 조건을 만족하는 경우의 수
+    pub covered: u64,
+    /// covered / cases × 100
+    pub coverage: f64,
+}
+
+impl GuaranteeCheck {
+    /// 모든 경우를 만족하는지 여부
+    pub fn holds(&self) -> bool {
+        self.covered == self.cases
+    }
+}
+
+/// 휠 생성 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Wheel {
+    /// 풀 번호 (키 번호 제외, 오름차순)
+    pub pool: Vec<u8>,
+    /// 모든 티켓에 들어가는 키 번호 (오름차순)
+    pub keys: Vec<u8>,
+    pub guarantee: WheelGuarantee,
+    pub tickets: Vec<[u8; 6]>,
+    /// 제외 정책에 걸려 빠진 티켓 수 (전체 휠만 해당)
+    pub excluded: usize,
+    /// 티켓 수 × 티켓 가격 (원)
+    pub cost: u64,
+    pub check: GuaranteeCheck,
+}
+
+/// 풀의 모든 조합으로 만든 전체 휠 (제외 대상 티켓은 빠지며, 그만큼 검증 결과가 100% 미만이 됩니다)
+pub fn full_wheel(game: &GameSpec, pool: &[u8], keys: &[u8], exclusion: &Exclusion) -> Result<Wheel, LotteryError> {
+    let (pool, keys) = wheel_numbers(game, pool, keys)?;
+    let slots = 6 - keys.len();
+    let key_mask = TicketMask::from_numbers(&keys);
+
+    let mut tickets = Vec::new();
+    let mut excluded = 0;
+    for_each_combination(&pool, slots, |block| match ticket_of(key_mask, TicketMask::from_numbers(block)) {
+        Some(ticket) if !exclusion.excludes(&ticket) => tickets.push(ticket),
+        _ => excluded += 1,
+    });
+
+    let guarantee = WheelGuarantee {
+        if_drawn: slots as u8,
+        matched: slots as u8,
+    };
+    Ok(finish(pool, keys, guarantee, tickets, excluded))
+}
+
+/// 보장 조건을 만족하는 축약 휠 (제외 대상 티켓은 후보에서 빠짐)
+pub fn abbreviated_wheel(
+    game: &GameSpec,
+    pool: &[u8],
+    keys: &[u8],
+    guarantee: WheelGuarantee,
+    exclusion: &Exclusion,
+) -> Result<Wheel, LotteryError> {
+    let (pool, keys) = wheel_numbers(game, pool, keys)?;
+    check_guarantee_bounds(&pool, &keys, guarantee)?;
+
+    let slots = 6 - keys.len();
+    let key_mask = TicketMask::from_numbers(&keys);
+    let if_drawn = guarantee.if_drawn as usize;
+    let matched = guarantee.matched as u32;
+
+    let mut uncovered = Vec::with_capacity(binomial(pool.len() as u64, if_drawn as u64) as usize);
+    for_each_combination(&pool, if_drawn, |case| uncovered.push(TicketMask::from_numbers(case)));
+
+    let choose: Vec<[usize; 7]> = (0..=MAX_WHEEL_POOL)
+        .map(|n| std::array::from_fn(|k| binomial(n as u64, k as u64) as usize))
+        .collect();
+    let mut blocks: Vec<TicketMask> = Vec::new();
+
+    while let Some(&target) = uncovered.first() {
+        let target_numbers: Vec<u8> = target.iter().collect();
+        let mut best: Option<(TicketMask, usize)> = None;
+
+        // matched == slots이면 고정한 번호가 곧 티켓이므로, 목표 경우의 부분집합 점수를 한 번에 셈
+        let subset_scores = (matched as usize == slots).then(|| subset_scores(target, &uncovered, slots, &choose));
+
+        // 목표 경우에서 matched개를 고정하고 나머지 자리를 하나씩 가장 많이 덮는 번호로 채움
+        for_each_combination(&target_numbers, matched as usize, |base| {
+            let mut block = TicketMask::from_numbers(base);
+            let (mut score, mut gains) = match &subset_scores {
+                Some(scores) => (scores[subset_rank(target, block, &choose)], [0; 64]),
+                None => cover_gains(block, &uncovered, matched),
+            };
+            while (block.len() as usize) < slots {
+                let last = block.len() as usize + 1 == slots;
+                let candidate = pool
+                    .iter()
+                    .filter(|&&num| !block.contains(num))
+                    .map(|&num| (block.with(num), gains[num as usize]))
+                    .filter(|&(next, _)| !last || usable(key_mask, next, exclusion, &blocks))
+                    .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)));
+
+                let Some((next, gain)) = candidate else {
+                    return;
+                };
+                block = next;
+                score += gain;
+                if !last {
+                    gains = cover_gains(block, &uncovered, matched).1;
+                }
+            }
+            if !usable(key_mask, block, exclusion, &blocks) {
+                return;
+            }
+
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((block, score));
+            }
+        });
+
+        match best {
+            Some((block, _)) => {
+                blocks.push(block);
+                uncovered.retain(|case| block.matches(*case) < matched);
+            }
+            // 제외 정책 때문에 덮을 수 없는 경우는 검증 결과에 미달로 남김
+            None => {
+                uncovered.remove(0);
+            }
+        }
+    }
+
+    let tickets = blocks.iter().filter_map(|&block| ticket_of(key_mask, block)).collect();
+    Ok(finish(pool, keys, guarantee, tickets, 0))
+}
+
+/// 티켓 목록이 보장 조건을 얼마나 만족하는지 검증 (키 번호는 모두 나왔다고 가정, 풀 번호끼리만 셈)
+pub fn check_guarantee(
+    game: &GameSpec,
+    pool: &[u8],
+    keys: &[u8],
+    tickets: &[[u8; 6]],
+    guarantee: WheelGuarantee,
+) -> Result<GuaranteeCheck, LotteryError> {
+    let (pool, keys) = wheel_numbers(game, pool, keys)?;
+    check_guarantee_bounds(&pool, &keys, guarantee)?;
+    Ok(coverage(&pool, tickets, guarantee))
+}
+
+// 키 번호와 풀 번호 검사 후 (키를 뺀 풀, 키) 정렬 반환
+pub(crate) fn wheel_numbers(game: &GameSpec, pool: &[u8], keys: &[u8]) -> Result<(Vec<u8>, Vec<u8>), LotteryError> {
+    if game.pick != 6 {
+        return Err(LotteryError::UnsupportedGame(game.name.clone()));
+    }
+    game.check_required(keys)?;
+    if keys.len() >= 6 {
+        return Err(LotteryError::InvalidInput("키 번호는 5개 이하여야 합니다.".to_string()));
+    }
+
+    let mut unique = TicketMask::EMPTY;
+    for &num in pool {
+        if !game.main_range().contains(&num) {
+            return Err(LotteryError::NumberOutOfRange { number: num, min: 1, max: game.pool });
+        }
+        if unique.contains(num) {
+            return Err(LotteryError::DuplicateNumber(num));
+        }
+        unique = unique.with(num);
+    }
+
+    let mut keys = keys.to_vec();
+    keys.sort();
+    let pool: Vec<u8> = unique.iter().filter(|num| !keys.contains(num)).collect();
+
+    let slots = 6 - keys.len();
+    if pool.len() < slots || pool.len() > MAX_WHEEL_POOL {
+        return Err(LotteryError::InvalidInput(format!(
+            "키 번호를 뺀 풀은 {}-{}개여야 합니다. ({}개)",
+            slots,
+            MAX_WHEEL_POOL,
+            pool.len()
+        )));
+    }
+
+    Ok((pool, keys))
+}
+
+pub(crate) fn check_guarantee_bounds(pool: &[u8], keys: &[u8], guarantee: WheelGuarantee) -> Result<(), LotteryError> {
+    let slots = 6 - keys.len();
+    let WheelGuarantee { if_drawn, matched } = guarantee;
+
+    if matched == 0 || matched > if_drawn || matched as usize > slots || if_drawn as usize > pool.len() {
+        return Err(LotteryError::InvalidInput(format!(
+            "보장 조건은 1 ≤ 일치 수({}) ≤ 추첨 수({}) ≤ 풀 크기({}), 일치 수 ≤ {}여야 합니다.",
+            matched,
+            if_drawn,
+            pool.len(),
+            slots
+        )));
+    }
+
+    Ok(())
+}
+
+// 제외 대상이 아니고 이미 고른 티켓과 다른지 여부
+pub(crate) fn usable(key_mask: TicketMask, block: TicketMask, exclusion: &Exclusion, blocks: &[TicketMask]) -> bool {
+    !blocks.contains(&block) && ticket_of(key_mask, block).is_some_and(|ticket| !exclusion.excludes(&ticket))
+}
+
+pub(crate) fn ticket_of(key_mask: TicketMask, block: TicketMask) -> Option<[u8; 6]> {
+    (key_mask | block).to_ticket()
+}
+
+// 블록이 덮는 경우 수와, 번호별로 그 번호를 더하면 새로 덮이는 경우 수 (남은 경우를 한 번만 훑음)
+fn cover_gains(block: TicketMask, uncovered: &[TicketMask], matched: u32) -> (usize, [usize; 64]) {
+    let mut covered = 0;
+    let mut gains = [0; 64];
+    for case in uncovered {
+        let hits = block.matches(*case);
+        if hits >= matched {
+            covered += 1;
+        } else if hits + 1 == matched {
+            for num in TicketMask(case.0 & !block.0).iter() {
+                gains[num as usize] += 1;
+            }
+        }
+    }
+    (covered, gains)
+}
+
+// 목표 경우의 k개 부분집합마다 그 부분집합을 포함하는 남은 경우 수 (subset_rank 순서)
+fn subset_scores(target: TicketMask, uncovered: &[TicketMask], k: usize, choose: &[[usize; 7]]) -> Vec<usize> {
+    let mut scores = vec![0; choose[target.len() as usize][k]];
+    for &case in uncovered {
+        let shared = case & target;
+        match (shared.len() as usize).cmp(&k) {
+            Ordering::Less => {}
+            Ordering::Equal => scores[subset_rank(target, shared, choose)] += 1,
+            Ordering::Greater => {
+                let numbers: Vec<u8> = shared.iter().collect();
+                for_each_combination(&numbers, k, |subset| {
+                    scores[subset_rank(target, TicketMask::from_numbers(subset), choose)] += 1;
+                });
+            }
+        }
+    }
+    scores
+}
+
+// 목표 경우 안의 위치로 센 부분집합의 조합 순위
+fn subset_rank(target: TicketMask, subset: TicketMask, choose: &[[usize; 7]]) -> usize {
+    let mut rank = 0;
+    let mut picked = 0;
+    for (position, num) in target.iter().enumerate() {
+        if subset.contains(num) {
+            picked += 1;
+            rank += choose[position][picked];
+        }
+    }
+    rank
+}
+
+fn coverage(pool: &[u8], tickets: &[[u8; 6]], guarantee: WheelGuarantee) -> GuaranteeCheck {
+    let masks: Vec<TicketMask> = tickets.iter().map(|ticket| TicketMask::from_numbers(ticket)).collect();
+    let mut cases = 0;
+    let mut covered = 0;
+
+    for_each_combination(pool, guarantee.if_drawn as usize, |case| {
+        let case = TicketMask::from_numbers(case);
+        cases += 1;
+        if masks.iter().any(|mask| mask.matches(case) >= guarantee.matched as u32) {
+            covered += 1;
+        }
+    });
+
+    GuaranteeCheck {
+        cases,
+        covered,
+        coverage: if cases == 0 { 100.0 } else { covered as f64 / cases as f64 * 100.0 },
+    }
+}
+
+pub(crate) fn finish(pool: Vec<u8>, keys: Vec<u8>, guarantee: WheelGuarantee, tickets: Vec<[u8; 6]>, excluded: usize) -> Wheel {
+    let check = coverage(&pool, &tickets, guarantee);
+    Wheel {
+        cost: tickets.len() as u64 * TICKET_PRICE,
+        pool,
+        keys,
+        guarantee,
+        tickets,
+        excluded,
+        check,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abbreviated_wheel_covers_the_largest_pool() {
+        let game = GameSpec::lotto_645();
+        let pool: Vec<u8> = (1..=20).collect();
+        let wheel = abbreviated_wheel(&game, &pool, &[], WheelGuarantee { if_drawn: 6, matched: 3 }, &Exclusion::none()).unwrap();
+        assert_eq!(wheel.check.cases, 38_760);
+        assert!(wheel.check.holds());
+
+        let wheel = abbreviated_wheel(&game, &pool[..10], &[], WheelGuarantee { if_drawn: 4, matched: 3 }, &Exclusion::none()).unwrap();
+        assert_eq!(wheel.tickets.len(), 4);
+        assert!(wheel.check.holds());
+
+        // 일치 수가 남은 자리 수와 같으면 고정한 번호가 곧 티켓
+        let wheel = abbreviated_wheel(&game, &pool[..12], &[], WheelGuarantee { if_drawn: 8, matched: 6 }, &Exclusion::none()).unwrap();
+        assert!(wheel.check.holds());
+    }
+}
@@ -3,6 +3,33 @@ import init, { LotteryEngine } from './pkg/lottery.js';
 let engine = null;
 let lotteryData = null;  // 역대 당첨번호 조회용
 
+// 생성에 사용할 시드 (URL의 ?seed=로 고정하면 같은 번호 재현)
+function nextSeed() {
+    const fixed = new URLSearchParams(window.location.search).get('seed');
+    return fixed !== null ? BigInt(fixed) : LotteryEngine.randomSeed();
+}
+
+// 과거 당첨 조합 제외 정책 (URL의 ?exclude=none|first|second|third|recent:N:K, 기본 second)
+function exclusionPolicy() {
+    return new URLSearchParams(window.location.search).get('exclude') ?? undefined;
+}
+
+const EXCLUSION_NOTES = {
+    none: '과거 당첨번호 제외 없음',
+    first: '기존 1등 당첨번호 제외',
+    second: '기존 1등, 2등 당첨번호 제외',
+    third: '기존 1등 번호와 5개 이상 겹치는 조합 제외',
+};
+
+function exclusionNote() {
+    const policy = exclusionPolicy() ?? 'second';
+    const recent = policy.match(/^recent:(\d+):(\d+)$/);
+    if (recent) {
+        return `최근 ${recent[1]}회 당첨번호와 ${recent[2]}개 이상 겹치는 조합 제외`;
+    }
+    return EXCLUSION_NOTES[policy] ?? policy;
+}
+
 async function loadLotteryData() {
     try {
         // 캐시 무효화를 위한 타임스탬프 추가 (Safari 대응)
@@ -41,7 +68,8 @@ window.showGenerateNumbers = function() {
 
     setTimeout(() => {
         try {
-            const sets = engine.generateNumbersSets();
+            const seed = nextSeed();
+            const sets = engine.generateNumbersSets(seed, exclusionPolicy());
 
             let html = '<div class="result-title">🎲 추천 로또 번호 5개 세트</div>';
 
@@ -56,11 +84,12 @@ window.showGenerateNumbers = function() {
                 `;
             });
 
-            html += '<div class="note">※ 기존 1등, 2등 당첨번호 제외</div>';
+            html += `<div class="note">※ ${exclusionNote()}<br>※ 시드: ${seed}</div>`;
+            html += oddsHtml(sets);
 
             content.innerHTML = html;
         } catch (error) {
-            content.innerHTML = `<div class="error-message">오류: ${error}</div>`;
+            content.innerHTML = `<div class="error-message">오류: ${formatError(error)}</div>`;
         }
     }, 300);
 };
@@ -68,15 +97,28 @@ window.showGenerateNumbers = function() {
 window.showGenerateWithRequired = function() {
     const content = document.getElementById('content');
     content.innerHTML = `
-        <div class="result-title">🎯 특정 번호 포함 생성</div>
+        <div class="result-title">🎯 특정 번호 포함/제외 생성</div>
         <form onsubmit="generateWithRequired(event)">
             <div class="form-group">
-                <label class="form-label">포함할 번호 (1-6개, 공백으로 구분)</label>
+                <label class="form-label">포함할 번호 (0-6개, 공백으로 구분)</label>
                 <input type="text"
                        id="required-numbers"
                        class="form-input"
-                       placeholder="예: 7 23 31"
-                       required>
+                       placeholder="예: 7 23 31">
+            </div>
+            <div class="form-group">
+                <label class="form-label">제외할 번호 (공백으로 구분)</label>
+                <input type="text"
+                       id="excluded-numbers"
+                       class="form-input"
+                       placeholder="예: 4 13 44">
+            </div>
+            <div class="form-group">
+                <label class="form-label">이 번호들 중에서만 선택 (비워두면 전체 번호)</label>
+                <input type="text"
+                       id="pool-numbers"
+                       class="form-input"
+                       placeholder="예: 1 3 7 9 12 15 18 21 24 27 30 33 36 39 42">
             </div>
             <button type="submit" class="submit-btn">번호 생성</button>
         </form>
@@ -87,20 +129,27 @@ window.showGenerateWithRequired = function() {
 window.generateWithRequired = function(event) {
     event.preventDefault();
 
-    const input = document.getElementById('required-numbers').value;
-    const numbers = input.trim().split(/\s+/).map(n => parseInt(n));
+    const parseNumbers = id => document.getElementById(id).value.trim().split(/\s+/).filter(n => n).map(n => parseInt(n));
+    const numbers = parseNumbers('required-numbers');
+    const excluded = parseNumbers('excluded-numbers');
+    const pool = parseNumbers('pool-numbers');
     const resultDiv = document.getElementById('result');
 
     // 유효성 검사
-    for (let num of numbers) {
+    for (let num of [...numbers, ...excluded, ...pool]) {
         if (isNaN(num) || num < 1 || num > 45) {
             resultDiv.innerHTML = '<div class="error-message">1-45 사이의 숫자만 입력해주세요.</div>';
             return;
         }
     }
 
-    if (numbers.length === 0 || numbers.length > 6) {
-        resultDiv.innerHTML = '<div class="error-message">1-6개의 번호를 입력해주세요.</div>';
+    if (numbers.length > 6) {
+        resultDiv.innerHTML = '<div class="error-message">포함할 번호는 6개까지 입력해주세요.</div>';
+        return;
+    }
+
+    if (numbers.length === 0 && excluded.length === 0 && pool.length === 0) {
+        resultDiv.innerHTML = '<div class="error-message">포함, 제외, 번호 풀 중 하나 이상 입력해주세요.</div>';
         return;
     }
 
@@ -108,9 +157,15 @@ window.generateWithRequired = function(event) {
 
     setTimeout(() => {
         try {
-            const sets = engine.generateNumbersSetsWithRequired(numbers);
+            const seed = nextSeed();
+            const constraints = { required: numbers, excluded, pool: pool.length > 0 ? pool : null };
+            const sets = engine.generateConstrainedSets(constraints, seed, exclusionPolicy());
 
-            let html = `<div class="success-message">포함된 번호: ${numbers.join(', ')}</div>`;
+            const conditions = [];
+            if (numbers.length > 0) conditions.push(`포함된 번호: ${numbers.join(', ')}`);
+            if (excluded.length > 0) conditions.push(`제외된 번호: ${excluded.join(', ')}`);
+            if (pool.length > 0) conditions.push(`번호 풀: ${pool.length}개`);
+            let html = `<div class="success-message">${conditions.join('<br>')}</div>`;
 
             sets.forEach((nums, index) => {
                 html += `
@@ -127,48 +182,269 @@ window.generateWithRequired = function(event) {
                 `;
             });
 
-            html += '<div class="note">※ 테두리 있는 번호는 지정한 번호입니다<br>※ 기존 1등, 2등 당첨번호 제외</div>';
+            html += `<div class="note">※ 테두리 있는 번호는 지정한 번호입니다<br>※ ${exclusionNote()}<br>※ 시드: ${seed}</div>`;
+            html += oddsHtml(sets);
 
             resultDiv.innerHTML = html;
         } catch (error) {
-            resultDiv.innerHTML = `<div class="error-message">오류: ${error}</div>`;
+            resultDiv.innerHTML = `<div class="error-message">오류: ${formatError(error)}</div>`;
         }
     }, 300);
 };
 
 window.showFrequency = function() {
     const content = document.getElementById('content');
-    content.innerHTML = '<div class="loading">분석 중...</div>';
+    content.innerHTML = `
+        <div class="result-title">📊 빈도 분석</div>
+        <form onsubmit="analyzeFrequency(event)">
+            <div class="form-group">
+                <label class="form-label">분석 구간 (비워두면 전체, 100: 최근 100회, 900-1000: 회차 범위)</label>
+                <input type="text" id="frequency-window" class="form-input" placeholder="예: 100">
+            </div>
+            <div class="form-group">
+                <label class="form-label">정렬</label>
+                <select id="frequency-sort-by" class="form-input">
+                    <option value="combined">합계 (본번호 + 보너스)</option>
+                    <option value="main">본번호</option>
+                    <option value="bonus">보너스</option>
+                </select>
+                <select id="frequency-order" class="form-input">
+                    <option value="ascending">적은 순</option>
+                    <option value="descending">많은 순</option>
+                </select>
+            </div>
+            <button type="submit" class="submit-btn">분석</button>
+        </form>
+        <div id="frequency-result"></div>
+        <div class="result-title">⏳ 미출현 갭 분석</div>
+        <form onsubmit="analyzeGaps(event)">
+            <div class="form-group">
+                <label class="form-label">과출 기준 백분위수 (현재 갭이 그 번호의 과거 갭 백분위수보다 크면 과출)</label>
+                <input type="number" id="gap-percentile" class="form-input" min="1" max="100" value="90">
+            </div>
+            <button type="submit" class="submit-btn">갭 분석</button>
+        </form>
+        <div id="gap-result"></div>
+    `;
+    analyzeFrequency();
+};
+
+// "100" → 최근 100회, "900-1000" → 회차 범위, 빈 값 → 전체
+function parseRoundWindow(value) {
+    const text = value.trim();
+    if (text === '') return { kind: 'all' };
+
+    const range = text.match(/^(\d+)\s*-\s*(\d+)$/);
+    if (range) return { kind: 'range', from: Number(range[1]), to: Number(range[2]) };
+    if (/^\d+$/.test(text)) return { kind: 'last', rounds: Number(text) };
+    return null;
+}
+
+window.analyzeFrequency = function(event) {
+    if (event) event.preventDefault();
+
+    const resultDiv = document.getElementById('frequency-result');
+    const roundWindow = parseRoundWindow(document.getElementById('frequency-window').value);
+    if (!roundWindow) {
+        resultDiv.innerHTML = '<div class="error-message">구간은 100 또는 900-1000 형식으로 입력해주세요.</div>';
+        return;
+    }
+
+    resultDiv.innerHTML = '<div class="loading">분석 중...</div>';
 
     setTimeout(() => {
         try {
-            const frequency = engine.getNumberFrequency();
+            const report = engine.getFrequencyReport({
+                window: roundWindow,
+                sortBy: document.getElementById('frequency-sort-by').value,
+                order: document.getElementById('frequency-order').value,
+            });
+            const signed = (value, digits) => (value >= 0 ? '+' : '') + value.toFixed(digits);
 
-            let html = '<div class="result-title">📊 빈도 분석 (낮은 순)</div>';
+            let html = `<div class="success-message">${report.fromRound}회 ~ ${report.toRound}회 (${report.draws}회 추첨)</div>`;
             html += '<div class="frequency-list">';
 
-            frequency.forEach(([number, count], index) => {
+            report.numbers.forEach(({ number, main, bonus, combined }, index) => {
                 html += `
                     <div class="frequency-item">
                         <div style="display: flex; align-items: center; gap: 15px;">
                             <div style="font-weight: 600; color: #999; width: 30px;">#${index + 1}</div>
                             <div class="frequency-number" style="background: ${getNumberColor(number)};">${number}</div>
                         </div>
-                        <div class="frequency-count">출현: ${count}회</div>
+                        <div class="frequency-count">
+                            본번호 ${main.count} · 보너스 ${bonus.count} · 합계 ${combined.count}회<br>
+                            편차 ${signed(combined.deviation, 1)} (z ${signed(combined.zScore, 2)})
+                        </div>
                     </div>
                 `;
             });
 
             html += '</div>';
-            html += '<div class="note">※ 출현 횟수가 적은 번호부터 표시</div>';
+            const expected = report.numbers[0];
+            html += `<div class="note">※ 기대 횟수: 본번호 ${expected.main.expected.toFixed(1)}회, 보너스 ${expected.bonus.expected.toFixed(1)}회, 합계 ${expected.combined.expected.toFixed(1)}회<br>※ 편차와 z는 합계 기준</div>`;
 
-            content.innerHTML = html;
+            resultDiv.innerHTML = html;
         } catch (error) {
-            content.innerHTML = `<div class="error-message">오류: ${error}</div>`;
+            resultDiv.innerHTML = `<div class="error-message">오류: ${formatError(error)}</div>`;
         }
-    }, 300);
+    }, 100);
+};
+
+window.analyzeGaps = function(event) {
+    if (event) event.preventDefault();
+
+    const resultDiv = document.getElementById('gap-result');
+    const percentile = parseFloat(document.getElementById('gap-percentile').value);
+    resultDiv.innerHTML = '<div class="loading">분석 중...</div>';
+
+    setTimeout(() => {
+        try {
+            const report = engine.getGapReport(Number.isNaN(percentile) ? undefined : percentile);
+            const overdueList = (numbers) => numbers.length
+                ? `<div class="lottery-numbers">${numbers.map(n => `<div class="lottery-number" style="background: ${getNumberColor(n)}; color: white;">${n}</div>`).join('')}</div>`
+                : '없음';
+            const dash = (value) => value === null || value === undefined ? '-' : value;
+
+            let html = `<div class="success-message">${report.latestRound}회 기준 (${report.draws}회 추첨)</div>`;
+            html += `<div class="form-label">본번호 과출: ${overdueList(report.overdueMain)}</div>`;
+            html += `<div class="form-label">보너스 과출: ${overdueList(report.overdueBonus)}</div>`;
+            html += '<div class="frequency-list">';
+
+            report.numbers.forEach(({ number, main, bonus }) => {
+                const status = [main.overdue ? '본번호 과출' : '', bonus.overdue ? '보너스 과출' : ''].filter(Boolean).join(' · ');
+                html += `
+                    <div class="frequency-item">
+                        <div style="display: flex; align-items: center; gap: 15px;">
+                            <div class="frequency-number" style="background: ${getNumberColor(number)};">${number}</div>
+                            <div style="font-weight: 600; color: #e74c3c;">${status}</div>
+                        </div>
+                        <div class="frequency-count">
+                            현재 ${main.current} · 최장 ${dash(main.longest)} · 평균 ${main.average === null ? '-' : main.average.toFixed(1)} · p${report.percentile} ${dash(main.threshold)}<br>
+                            보너스 현재 ${bonus.current} · 최장 ${dash(bonus.longest)}
+                        </div>
+                    </div>
+                `;
+            });
+
+            html += '</div>';
+            html += '<div class="note">※ 갭 = 번호가 나오지 않은 회차 수 (연달아 나오면 0)<br>※ 과출은 과거 패턴 대비 오래 안 나왔다는 뜻일 뿐, 다음 회차 확률은 모든 번호가 같습니다.</div>';
+
+            resultDiv.innerHTML = html;
+        } catch (error) {
+            resultDiv.innerHTML = `<div class="error-message">오류: ${formatError(error)}</div>`;
+        }
+    }, 100);
 };
 
+window.showCooccurrence = function() {
+    const content = document.getElementById('content');
+    content.innerHTML = `
+        <div class="result-title">🔗 동시출현 분석</div>
+        <form onsubmit="analyzeCooccurrence(event)">
+            <div class="form-group">
+                <label class="form-label">분석 구간 (비워두면 전체, 100: 최근 100회, 900-1000: 회차 범위)</label>
+                <input type="text" id="cooccurrence-window" class="form-input" placeholder="예: 100">
+            </div>
+            <div class="form-group">
+                <label class="form-label"><input type="checkbox" id="cooccurrence-triples"> 삼중 동시출현도 분석</label>
+            </div>
+            <button type="submit" class="submit-btn">분석</button>
+        </form>
+        <div id="cooccurrence-result"></div>
+    `;
+    analyzeCooccurrence();
+};
+
+window.analyzeCooccurrence = function(event) {
+    if (event) event.preventDefault();
+
+    const resultDiv = document.getElementById('cooccurrence-result');
+    const roundWindow = parseRoundWindow(document.getElementById('cooccurrence-window').value);
+    if (!roundWindow) {
+        resultDiv.innerHTML = '<div class="error-message">구간은 100 또는 900-1000 형식으로 입력해주세요.</div>';
+        return;
+    }
+
+    resultDiv.innerHTML = '<div class="loading">분석 중...</div>';
+
+    setTimeout(() => {
+        try {
+            const report = engine.getCooccurrenceReport({
+                window: roundWindow,
+                triples: document.getElementById('cooccurrence-triples').checked,
+            });
+            const max = Math.max(1, ...report.matrix.flat());
+            const pairList = (pairs) => pairs
+                .map(({ numbers, stats }) => `${numbers.join('-')} (${stats.count}회, z ${stats.zScore >= 0 ? '+' : ''}${stats.zScore.toFixed(2)})`)
+                .join(', ');
+
+            let html = `<div class="success-message">${report.fromRound}회 ~ ${report.toRound}회 (${report.draws}회 추첨), 쌍 하나의 기대 횟수 ${report.pairExpected.toFixed(1)}회</div>`;
+
+            // 히트맵: 진할수록 함께 많이 나온 쌍 (칸에 마우스를 올리면 횟수 표시)
+            const size = report.matrix.length;
+            html += `<div style="display: grid; grid-template-columns: 24px repeat(${size}, 1fr); gap: 1px; font-size: 9px; margin: 15px 0;">`;
+            html += '<div></div>';
+            for (let b = 1; b <= size; b++) html += `<div style="text-align: center; color: #999;">${b}</div>`;
+            report.matrix.forEach((row, i) => {
+                html += `<div style="color: #999; text-align: right; padding-right: 3px;">${i + 1}</div>`;
+                row.forEach((count, j) => {
+                    const alpha = i === j ? 0 : count / max;
+                    html += `<div title="${i + 1}-${j + 1}: ${count}회" style="aspect-ratio: 1; background: rgba(102, 126, 234, ${alpha.toFixed(3)});"></div>`;
+                });
+            });
+            html += '</div>';
+
+            html += `<div class="form-label">많이 나온 쌍: ${pairList(report.mostCommonPairs)}</div>`;
+            html += `<div class="form-label">적게 나온 쌍 (1회 이상): ${pairList(report.leastCommonPairs)}</div>`;
+            html += `<div class="form-label">한 번도 함께 나오지 않은 쌍 (${report.neverPaired.length}개): ${report.neverPaired.map(pair => pair.join('-')).join(', ') || '없음'}</div>`;
+
+            if (report.triples) {
+                const triples = report.triples;
+                html += `<div class="form-label">많이 나온 삼중 (기대 ${triples.expected.toFixed(2)}회): ${triples.mostCommon.map(({ numbers, stats }) => `${numbers.join('-')} (${stats.count}회)`).join(', ')}</div>`;
+                html += `<div class="form-label">한 번도 함께 나오지 않은 삼중: ${triples.neverAppeared} / ${triples.total}개</div>`;
+            }
+
+            html += '<div class="note">※ 본번호 기준 (보너스 제외)<br>※ 동시출현은 과거 기록일 뿐, 다음 회차에 특정 쌍이 나올 확률은 모두 같습니다.</div>';
+            resultDiv.innerHTML = html;
+        } catch (error) {
+            resultDiv.innerHTML = `<div class="error-message">오류: ${formatError(error)}</div>`;
+        }
+    }, 100);
+};
+
+// 생성된 세트의 당첨 확률 (일치 개수별 확률, 묶음 당첨 확률, 기다리는 회차 수)
+function oddsHtml(sets) {
+    const report = engine.getOdds(sets);
+    const oneIn = p => `1/${Math.round(1 / p).toLocaleString()}`;
+
+    let html = '<div class="result-title">📐 당첨 확률</div>';
+    report.tiers.forEach(tier => {
+        const rank = ['first', 'second', 'third', 'fourth', 'fifth'].indexOf(tier.tier) + 1;
+        html += `
+            <div class="frequency-item">
+                <div>${rank}등 이상 · ${report.tickets}장 ${oneIn(tier.probability)} (1장 ${oneIn(tier.single)})</div>
+                <div class="frequency-count">평균 ${Math.round(tier.expectedDraws).toLocaleString()}회 · 중앙값 ${Math.round(tier.medianDraws).toLocaleString()}회 · 약 ${tier.expectedYears.toFixed(1)}년</div>
+            </div>
+        `;
+    });
+
+    const matches = [];
+    for (let matched = 6; matched >= 3; matched--) {
+        const entries = report.matches.filter(m => m.matched === matched);
+        const probability = entries.reduce((sum, m) => sum + m.probability, 0);
+        const bonus = entries.filter(m => m.bonusMatched > 0).reduce((sum, m) => sum + m.probability, 0);
+        matches.push(`${matched}개 ${oneIn(probability)}` + (matched === 5 && bonus > 0 ? ` (보너스 일치 ${oneIn(bonus)})` : ''));
+    }
+
+    html += `<div class="note">※ 티켓 1장 일치 확률: ${matches.join(', ')}<br>※ 묶음 확률은 세트끼리 겹치는 번호(서로 다른 번호 ${report.distinctNumbers}개)를 반영한 값입니다<br>※ 회차 수는 매 회차 같은 세트를 산다고 볼 때, 연수는 주 1회 추첨 기준</div>`;
+    return html;
+}
+
+// WASM 오류 객체({ code, message })를 표시용 문자열로 변환
+function formatError(error) {
+    return error && error.message ? error.message : String(error);
+}
+
 // 번호 색상 결정 함수
 function getNumberColor(num) {
     if (num <= 10) return '#fbc400';      // 노랑
@@ -244,5 +520,277 @@ window.displayWinningNumber = function() {
     `;
 };
 
+// 당첨 확인
+window.showCheckTickets = function() {
+    const content = document.getElementById('content');
+
+    if (!lotteryData || lotteryData.length === 0) {
+        content.innerHTML = '<div class="error-message">데이터를 불러올 수 없습니다.</div>';
+        return;
+    }
+
+    const sortedData = [...lotteryData].sort((a, b) => b.round - a.round);
+    const options = sortedData.map(d =>
+        `<option value="${d.round}">${d.round}회</option>`
+    ).join('');
+
+    content.innerHTML = `
+        <div class="result-title">✅ 당첨 확인</div>
+        <form onsubmit="checkTickets(event)">
+            <div class="form-group">
+                <label class="form-label">회차 선택</label>
+                <select id="check-round" class="form-input">
+                    ${options}
+                </select>
+            </div>
+            <div class="form-group">
+                <label class="form-label">내 번호 (한 줄에 6개씩, 공백으로 구분)</label>
+                <textarea id="check-tickets"
+                          class="form-input"
+                          rows="5"
+                          placeholder="예: 3 12 18 25 31 44"
+                          required></textarea>
+            </div>
+            <button type="submit" class="submit-btn">확인</button>
+        </form>
+        <div id="check-result"></div>
+    `;
+};
+
+window.checkTickets = function(event) {
+    event.preventDefault();
+
+    const round = parseInt(document.getElementById('check-round').value);
+    const tickets = document.getElementById('check-tickets').value
+        .split('\n')
+        .map(line => line.trim())
+        .filter(line => line.length > 0)
+        .map(line => line.split(/\s+/).map(n => parseInt(n)));
+    const resultDiv = document.getElementById('check-result');
+
+    try {
+        const results = engine.checkTickets(tickets, round);
+        const drawing = lotteryData.find(d => d.round === round);
+
+        let html = '';
+        results.forEach((result, index) => {
+            const tier = result.tier === 'none' ? '낙첨' : `${['first', 'second', 'third', 'fourth', 'fifth'].indexOf(result.tier) + 1}등`;
+            html += `
+                <div class="lottery-set">
+                    <div class="lottery-set-header">티켓 ${index + 1} · ${result.matched}개 일치 · ${tier}</div>
+                    <div class="lottery-numbers">
+                        ${result.numbers.map(num => {
+                            const hit = drawing.numbers.includes(num);
+                            const bonusHit = num === drawing.bonus;
+                            const borderStyle = hit ? 'border: 3px solid white;' : (bonusHit ? 'border: 3px dashed white;' : '');
+                            const opacity = hit || bonusHit ? '' : 'opacity: 0.4;';
+                            return `<div class="lottery-number" style="background: ${getNumberColor(num)}; color: white; ${borderStyle} ${opacity}">${num}</div>`;
+                        }).join('')}
+                    </div>
+                </div>
+            `;
+        });
+        html += `<div class="note">※ ${round}회 당첨번호: ${drawing.numbers.join(', ')} + 보너스 ${drawing.bonus}</div>`;
+
+        resultDiv.innerHTML = html;
+    } catch (error) {
+        resultDiv.innerHTML = `<div class="error-message">오류: ${formatError(error)}</div>`;
+    }
+};
+
+// 티켓 1장의 기대값
+window.showExpectedValue = function() {
+    const content = document.getElementById('content');
+    content.innerHTML = `
+        <div class="result-title">💰 기대값 계산 (티켓 1장)</div>
+        <form onsubmit="calculateExpectedValue(event)">
+            <div class="form-group">
+                <label class="form-label">1등 예상 총 당첨금 (원, 이월금 포함)</label>
+                <input type="number" id="ev-pool" class="form-input" value="25000000000" min="0" required>
+            </div>
+            <div class="form-group">
+                <label class="form-label">1등 예상 당첨자 수 (본인 제외)</label>
+                <input type="number" id="ev-co-winners" class="form-input" value="11" min="0" step="any" required>
+            </div>
+            <button type="submit" class="submit-btn">계산</button>
+        </form>
+        <div id="ev-result"></div>
+    `;
+};
+
+window.calculateExpectedValue = function(event) {
+    event.preventDefault();
+
+    const resultDiv = document.getElementById('ev-result');
+    const pool = Number(document.getElementById('ev-pool').value);
+    const coWinners = Number(document.getElementById('ev-co-winners').value);
+
+    try {
+        const report = engine.expectedValue({ first: { pool, coWinners } });
+        const won = value => `${Math.round(value).toLocaleString()}원`;
+
+        let html = '';
+        report.tiers.forEach(tier => {
+            const rank = ['first', 'second', 'third', 'fourth', 'fifth'].indexOf(tier.tier) + 1;
+            html += `
+                <div class="frequency-item">
+                    <div>${rank}등 · 1/${Math.round(1 / tier.probability).toLocaleString()}</div>
+                    <div class="frequency-count">세전 ${won(tier.grossPrize)} · 세후 ${won(tier.netPrize)}</div>
+                </div>
+            `;
+        });
+
+        const verdict = report.netEv > 0 ? '세후 기대값이 티켓 가격보다 큽니다' : '세후 기대값이 티켓 가격보다 작습니다';
+        html += `
+            <div class="success-message">
+                기대 당첨금: 세전 ${report.grossReturn.toFixed(1)}원 / 세후 ${report.netReturn.toFixed(1)}원<br>
+                회수율: 세전 ${(report.grossReturnRate * 100).toFixed(1)}% / 세후 ${(report.netReturnRate * 100).toFixed(1)}%
+            </div>
+            <div class="note">※ ${verdict}<br>※ 2, 3등 당첨금 풀과 4, 5등 당첨금은 기본 예상치 사용</div>
+        `;
+
+        resultDiv.innerHTML = html;
+    } catch (error) {
+        resultDiv.innerHTML = `<div class="error-message">오류: ${formatError(error)}</div>`;
+    }
+};
+
+// 점수 기반 추천 (전체 빈도 + 최근 50회 + 미출현 갭 + 페어 동시출현)
+window.showRecommend = function() {
+    const content = document.getElementById('content');
+    content.innerHTML = '<div class="loading">후보 생성 중...</div>';
+
+    setTimeout(() => {
+        try {
+            const seed = nextSeed();
+            const { tickets, stats } = engine.recommendDiverse(10, undefined, undefined, undefined, seed, exclusionPolicy());
+            const latest = lotteryData.reduce((a, b) => (a.round > b.round ? a : b));
+
+            let html = '<div class="result-title">⭐ 점수 기반 추천 10개</div>';
+            tickets.forEach((ticket, index) => {
+                const sum = ticket.numbers.reduce((a, b) => a + b, 0);
+                const odd = ticket.numbers.filter(n => n % 2 === 1).length;
+                html += `
+                    <div class="lottery-set">
+                        <div class="lottery-set-header">${index + 1}위 · 점수 ${ticket.score.toFixed(3)} · 합계 ${sum} · 홀짝 ${odd}:${6 - odd}</div>
+                        <div class="lottery-numbers">
+                            ${ticket.numbers.map(num => `<div class="lottery-number" style="background: ${getNumberColor(num)}; color: white;">${num}</div>`).join('')}
+                        </div>
+                    </div>
+                `;
+            });
+
+            const usage = stats.numberCounts.map(([number, count]) => `${number}(${count})`).join(', ');
+            html += `<div class="success-message">사용된 고유 번호: ${stats.uniqueNumbers}/45<br>번호별 등장: ${usage}</div>`;
+            html += `<div class="note">※ 번호 하나는 최대 4장까지, 재사용할수록 감점해 고르게 섞음<br>※ ${latest.round}회까지의 데이터 기준<br>※ 합계 100-175, 홀짝·저고 2:4~4:2, 연속 쌍 1개 이하, 직전 회차 겹침 1개 이하<br>※ ${exclusionNote()}<br>※ 시드: ${seed}</div>`;
+
+            content.innerHTML = html;
+        } catch (error) {
+            content.innerHTML = `<div class="error-message">오류: ${formatError(error)}</div>`;
+        }
+    }, 300);
+};
+
+window.showWheel = function() {
+    const content = document.getElementById('content');
+    content.innerHTML = `
+        <div class="result-title">🎡 휠링 (조합 보장)</div>
+        <form onsubmit="generateWheel(event)">
+            <div class="form-group">
+                <label class="form-label">풀 번호 (최대 20개, 공백으로 구분)</label>
+                <input type="text" id="wheel-pool" class="form-input" placeholder="예: 3 7 12 18 23 29 34 41 44 45" required>
+            </div>
+            <div class="form-group">
+                <label class="form-label">키 번호 (모든 티켓에 포함, 비워두면 없음)</label>
+                <input type="text" id="wheel-keys" class="form-input" placeholder="예: 7">
+            </div>
+            <div class="form-group">
+                <label class="form-label">보장 조건 (풀에서 N개가 나오면 M개 일치, 비워두면 전체 휠)</label>
+                <input type="number" id="wheel-if-drawn" class="form-input" placeholder="N (예: 4)" min="1" max="20">
+                <input type="number" id="wheel-matched" class="form-input" placeholder="M (예: 3)" min="1" max="6">
+            </div>
+            <div class="form-group">
+                <label class="form-label">더 적은 티켓 탐색 시간 (초, 보장 조건이 있을 때만, 비워두면 탐색 안 함)</label>
+                <input type="number" id="wheel-search-seconds" class="form-input" placeholder="예: 3" min="1" max="10">
+            </div>
+            <button type="submit" class="submit-btn">티켓 만들기</button>
+        </form>
+        <div id="wheel-result"></div>
+    `;
+};
+
+window.generateWheel = function(event) {
+    event.preventDefault();
+
+    const parseNumbers = id => document.getElementById(id).value.trim().split(/\s+/).filter(n => n).map(n => parseInt(n));
+    const pool = parseNumbers('wheel-pool');
+    const keys = parseNumbers('wheel-keys');
+    const ifDrawn = document.getElementById('wheel-if-drawn').value;
+    const matched = document.getElementById('wheel-matched').value;
+    const searchSeconds = document.getElementById('wheel-search-seconds').value;
+    const resultDiv = document.getElementById('wheel-result');
+
+    if ([...pool, ...keys].some(num => isNaN(num))) {
+        resultDiv.innerHTML = '<div class="error-message">숫자만 입력해주세요.</div>';
+        return;
+    }
+
+    resultDiv.innerHTML = '<div class="loading">티켓 계산 중...</div>';
+
+    setTimeout(() => {
+        try {
+            const guaranteed = ifDrawn !== '' && matched !== '';
+            const searched = guaranteed && Number(searchSeconds) > 0
+                ? engine.searchCovering(
+                    pool,
+                    keys,
+                    Number(ifDrawn),
+                    Number(matched),
+                    { timeBudgetMs: Math.min(Number(searchSeconds), 10) * 1000 },
+                    exclusionPolicy()
+                )
+                : null;
+            const wheel = searched ? searched.wheel : engine.wheel(
+                pool,
+                keys,
+                guaranteed ? Number(ifDrawn) : undefined,
+                guaranteed ? Number(matched) : undefined,
+                exclusionPolicy()
+            );
+            const { ifDrawn: drawn, matched: hit } = wheel.guarantee;
+
+            let html = '';
+            wheel.tickets.forEach((ticket, index) => {
+                html += `
+                    <div class="lottery-set">
+                        <div class="lottery-set-header">${index + 1}번 티켓</div>
+                        <div class="lottery-numbers">
+                            ${ticket.map(num => `<div class="lottery-number" style="background: ${getNumberColor(num)}; color: white;">${num}</div>`).join('')}
+                        </div>
+                    </div>
+                `;
+            });
+
+            const guarantee = wheel.keys.length === 0
+                ? `풀에서 ${drawn}개가 나오면 최소 ${hit}개 일치`
+                : `키 번호 ${wheel.keys.join(', ')}가 모두 나오고 풀에서 ${drawn}개가 나오면 최소 ${hit + wheel.keys.length}개 일치`;
+            html += `
+                <div class="success-message">
+                    티켓 ${wheel.tickets.length}장 · ${wheel.cost.toLocaleString()}원<br>
+                    보장: ${guarantee}<br>
+                    검증: ${wheel.check.covered}/${wheel.check.cases} 경우 만족 (${wheel.check.coverage.toFixed(2)}%)
+                    ${searched ? `<br>탐색: 축약 휠 ${searched.initialTickets}장 → ${wheel.tickets.length}장 (${searched.iterations.toLocaleString()}회 이동, ${(searched.elapsedMs / 1000).toFixed(1)}초)` : ''}
+                </div>
+            `;
+            const excluded = wheel.excluded > 0 ? `<br>※ 제외 정책으로 빠진 티켓 ${wheel.excluded}장` : '';
+            html += `<div class="note">※ ${exclusionNote()}${excluded}</div>`;
+
+            resultDiv.innerHTML = html;
+        } catch (error) {
+            resultDiv.innerHTML = `<div class="error-message">오류: ${formatError(error)}</div>`;
+        }
+    }, 100);
+};
+
 // 초기화
 initialize();